@@ -3,13 +3,49 @@ extern crate gl_generator;
 use gl_generator::{Api, Fallbacks, GlobalGenerator, Profile, Registry};
 use std::env;
 use std::fs::File;
+use std::io::Write;
 use std::path::Path;
 
+// Cargo feature -> registry name, and whether GLES has it. Desktop only
+// extensions are left out of GLES bindings.
+const EXTENSIONS: [(&str, &str, bool); 7] = [
+    ("KHR_debug", "GL_KHR_debug", true),
+    ("ARB_direct_state_access", "GL_ARB_direct_state_access", false),
+    ("ARB_bindless_texture", "GL_ARB_bindless_texture", false),
+    ("ARB_tessellation_shader", "GL_ARB_tessellation_shader", false),
+    ("ARB_compute_shader", "GL_ARB_compute_shader", false),
+    ("ARB_shader_image_load_store", "GL_ARB_shader_image_load_store", false),
+    ("ARB_buffer_storage", "GL_ARB_buffer_storage", false),
+];
+
+fn feature(name: &str) -> bool {
+    env::var_os(format!("CARGO_FEATURE_{}", name.to_uppercase())).is_some()
+}
+
 fn main() {
     let out_dir = env::var("OUT_DIR").unwrap();
     let mut file = File::create(&Path::new(&out_dir).join("bindings.rs")).unwrap();
 
-    Registry::new(Api::Gl, (3, 3), Profile::Core, Fallbacks::All, [])
+    let (api, version, profile) = if feature("gles30") {
+        (Api::Gles2, (3, 0), Profile::Core)
+    } else if feature("gl45") {
+        (Api::Gl, (4, 5), Profile::Core)
+    } else if feature("gl43") {
+        (Api::Gl, (4, 3), Profile::Core)
+    } else {
+        (Api::Gl, (3, 3), Profile::Core)
+    };
+    let extensions: Vec<&str> = EXTENSIONS.iter()
+        .filter(|&&(feature_name, _, on_gles)| feature(feature_name) && (on_gles || api != Api::Gles2))
+        .map(|&(_, extension, _)| extension)
+        .collect();
+
+    Registry::new(api, version, profile, Fallbacks::All, extensions.clone())
         .write_bindings(GlobalGenerator, &mut file)
         .unwrap();
-}
\ No newline at end of file
+
+    // What the bindings were generated for, so the code using them can check
+    writeln!(file, "pub const TARGET_VERSION: (u8, u8) = {:?};", version).unwrap();
+    writeln!(file, "pub const TARGET_GLES: bool = {};", api == Api::Gles2).unwrap();
+    writeln!(file, "pub const TARGET_EXTENSIONS: &[&str] = &{:?};", extensions).unwrap();
+}
@@ -2,9 +2,5 @@
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 
-// Commented since file generated
-//include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
-
-// For autocompletion I use the file here
-pub use bindings::*;
-mod bindings;
\ No newline at end of file
+// Generated by build.rs for the version and extensions picked with cargo features
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
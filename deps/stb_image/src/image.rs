@@ -0,0 +1,458 @@
+use std;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::{c_int, c_void};
+
+use bindings::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    InvalidPath(String),
+    InvalidChannels(i32),
+    TooLarge(usize),
+    // Reason reported by stbi_failure_reason
+    Decode(String),
+    // Pixel data given to an encoder doesn't match the size
+    InvalidSize { expected: usize, actual: usize },
+    Write(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::InvalidPath(ref path) => write!(f, "Invalid image path \"{}\"", path),
+            Error::InvalidChannels(n) => write!(f, "Channel count must be 0-4, got {}", n),
+            Error::TooLarge(len) => write!(f, "Image data of {} bytes is too large", len),
+            Error::Decode(ref reason) => write!(f, "Couldn't decode image: {}", reason),
+            Error::InvalidSize { expected, actual } =>
+                write!(f, "Expected {} bytes of pixel data, got {}", expected, actual),
+            Error::Write(ref reason) => write!(f, "Couldn't write image: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Info {
+    pub width: i32,
+    pub height: i32,
+    pub channels: i32,
+}
+
+// Decoded pixels owned by stb_image, freed on drop.
+// T is u8 for `load`, u16 for `load_16` and f32 for `loadf`.
+pub struct Image<T> {
+    data: *mut T,
+    width: i32,
+    height: i32,
+    channels: i32,
+    channels_in_file: i32,
+}
+
+// The buffer is plain malloc'd memory, so it can be freed from any thread
+unsafe impl<T: Send> Send for Image<T> {}
+unsafe impl<T: Sync> Sync for Image<T> {}
+
+pub trait Component: Copy {
+    fn premultiply(self, alpha: Self) -> Self;
+}
+
+impl Component for u8 {
+    fn premultiply(self, alpha: u8) -> u8 {
+        ((self as u32 * alpha as u32 + 127) / 255) as u8
+    }
+}
+
+impl Component for u16 {
+    fn premultiply(self, alpha: u16) -> u16 {
+        ((self as u32 * alpha as u32 + 32767) / 65535) as u16
+    }
+}
+
+impl Component for f32 {
+    fn premultiply(self, alpha: f32) -> f32 {
+        self * alpha
+    }
+}
+
+// Post processing done in Rust instead of through stb's global flags
+// (stbi_set_flip_vertically_on_load etc.), so it's safe to load on any thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LoadOptions {
+    pub desired_channels: i32,
+    pub flip_vertically: bool,
+    pub premultiply_alpha: bool,
+}
+
+impl LoadOptions {
+    pub fn load(&self, path: &str) -> Result<Image<u8>, Error> {
+        Image::load(path, self.desired_channels).map(|image| self.apply(image))
+    }
+
+    pub fn load_from_memory(&self, bytes: &[u8]) -> Result<Image<u8>, Error> {
+        Image::from_memory(bytes, self.desired_channels).map(|image| self.apply(image))
+    }
+
+    pub fn load_16(&self, path: &str) -> Result<Image<u16>, Error> {
+        Image::load_16(path, self.desired_channels).map(|image| self.apply(image))
+    }
+
+    pub fn loadf(&self, path: &str) -> Result<Image<f32>, Error> {
+        Image::loadf(path, self.desired_channels).map(|image| self.apply(image))
+    }
+
+    pub fn apply<T: Component>(&self, mut image: Image<T>) -> Image<T> {
+        if self.flip_vertically {
+            image.flip_vertically();
+        }
+        if self.premultiply_alpha {
+            image.premultiply_alpha();
+        }
+        image
+    }
+}
+
+impl Image<u8> {
+    // desired_channels of 0 keeps the channel count of the file
+    pub fn load(path: &str, desired_channels: i32) -> Result<Self, Error> {
+        let path = c_path(path)?;
+        decode(desired_channels, |x, y, n| unsafe {
+            stbi_load(path.as_ptr(), x, y, n, desired_channels)
+        })
+    }
+
+    pub fn from_memory(bytes: &[u8], desired_channels: i32) -> Result<Self, Error> {
+        let len = c_len(bytes)?;
+        decode(desired_channels, |x, y, n| unsafe {
+            stbi_load_from_memory(bytes.as_ptr(), len, x, y, n, desired_channels)
+        })
+    }
+}
+
+impl Image<u16> {
+    pub fn load_16(path: &str, desired_channels: i32) -> Result<Self, Error> {
+        let path = c_path(path)?;
+        decode(desired_channels, |x, y, n| unsafe {
+            stbi_load_16(path.as_ptr(), x, y, n, desired_channels)
+        })
+    }
+
+    pub fn load_16_from_memory(bytes: &[u8], desired_channels: i32) -> Result<Self, Error> {
+        let len = c_len(bytes)?;
+        decode(desired_channels, |x, y, n| unsafe {
+            stbi_load_16_from_memory(bytes.as_ptr(), len, x, y, n, desired_channels)
+        })
+    }
+}
+
+impl Image<f32> {
+    // Hdr files keep their range, ldr files are converted with stb's default gamma
+    pub fn loadf(path: &str, desired_channels: i32) -> Result<Self, Error> {
+        let path = c_path(path)?;
+        decode(desired_channels, |x, y, n| unsafe {
+            stbi_loadf(path.as_ptr(), x, y, n, desired_channels)
+        })
+    }
+
+    pub fn loadf_from_memory(bytes: &[u8], desired_channels: i32) -> Result<Self, Error> {
+        let len = c_len(bytes)?;
+        decode(desired_channels, |x, y, n| unsafe {
+            stbi_loadf_from_memory(bytes.as_ptr(), len, x, y, n, desired_channels)
+        })
+    }
+}
+
+impl<T> Image<T> {
+    pub fn width(&self) -> i32 { self.width }
+    pub fn height(&self) -> i32 { self.height }
+    // Channels in the returned buffer
+    pub fn channels(&self) -> i32 { self.channels }
+    pub fn channels_in_file(&self) -> i32 { self.channels_in_file }
+
+    pub fn len(&self) -> usize {
+        self.width as usize * self.height as usize * self.channels as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.data, self.len()) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.data, self.len()) }
+    }
+
+    // Puts the last row first, which is what gl expects
+    pub fn flip_vertically(&mut self) {
+        let row_len = self.width as usize * self.channels as usize;
+        let height = self.height as usize;
+        let data = self.as_mut_slice();
+        for row in 0..height / 2 {
+            let (top, bottom) = data.split_at_mut((height - row - 1) * row_len);
+            top[row * row_len..(row + 1) * row_len].swap_with_slice(&mut bottom[..row_len]);
+        }
+    }
+}
+
+impl<T: Component> Image<T> {
+    // Multiplies color by alpha for 2 (grey + alpha) and 4 channel images, others are left as is
+    pub fn premultiply_alpha(&mut self) {
+        let channels = self.channels as usize;
+        if channels != 2 && channels != 4 {
+            return;
+        }
+        for pixel in self.as_mut_slice().chunks_mut(channels) {
+            let alpha = pixel[channels - 1];
+            for c in pixel[..channels - 1].iter_mut() {
+                *c = c.premultiply(alpha);
+            }
+        }
+    }
+}
+
+impl<T> Drop for Image<T> {
+    fn drop(&mut self) {
+        unsafe {
+            stbi_image_free(self.data as *mut c_void);
+        }
+    }
+}
+
+impl<T> fmt::Debug for Image<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Image")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("channels", &self.channels)
+            .field("channels_in_file", &self.channels_in_file)
+            .finish()
+    }
+}
+
+// Reads the dimensions and channel count without decoding the pixels
+pub fn info(path: &str) -> Result<Info, Error> {
+    let path = c_path(path)?;
+    query_info(|x, y, n| unsafe { stbi_info(path.as_ptr(), x, y, n) })
+}
+
+pub fn info_from_memory(bytes: &[u8]) -> Result<Info, Error> {
+    let len = c_len(bytes)?;
+    query_info(|x, y, n| unsafe { stbi_info_from_memory(bytes.as_ptr(), len, x, y, n) })
+}
+
+pub fn is_hdr(path: &str) -> Result<bool, Error> {
+    let path = c_path(path)?;
+    Ok(unsafe { stbi_is_hdr(path.as_ptr()) } != 0)
+}
+
+fn decode<T, F>(desired_channels: i32, load: F) -> Result<Image<T>, Error>
+    where F: FnOnce(&mut c_int, &mut c_int, &mut c_int) -> *mut T
+{
+    if !(0..=4).contains(&desired_channels) {
+        return Err(Error::InvalidChannels(desired_channels));
+    }
+    let (mut width, mut height, mut channels_in_file) = (0, 0, 0);
+    let data = load(&mut width, &mut height, &mut channels_in_file);
+    if data.is_null() {
+        return Err(Error::Decode(failure_reason()));
+    }
+    Ok(Image {
+        data,
+        width,
+        height,
+        channels: if desired_channels == 0 { channels_in_file } else { desired_channels },
+        channels_in_file,
+    })
+}
+
+fn query_info<F>(query: F) -> Result<Info, Error>
+    where F: FnOnce(&mut c_int, &mut c_int, &mut c_int) -> c_int
+{
+    let (mut width, mut height, mut channels) = (0, 0, 0);
+    if query(&mut width, &mut height, &mut channels) == 0 {
+        return Err(Error::Decode(failure_reason()));
+    }
+    Ok(Info { width, height, channels })
+}
+
+fn c_path(path: &str) -> Result<CString, Error> {
+    CString::new(path).map_err(|_| Error::InvalidPath(path.to_string()))
+}
+
+fn c_len(bytes: &[u8]) -> Result<c_int, Error> {
+    if bytes.len() > c_int::MAX as usize {
+        return Err(Error::TooLarge(bytes.len()));
+    }
+    Ok(bytes.len() as c_int)
+}
+
+// The reason for this thread's last failure, stb_image.c makes it thread local
+pub fn failure_reason() -> String {
+    unsafe {
+        let reason = stbi_failure_reason();
+        if reason.is_null() {
+            String::from("unknown error")
+        } else {
+            CStr::from_ptr(reason).to_string_lossy().into_owned()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    const CONTAINER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../Resources/container.jpg");
+    const FACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../Resources/awesomeface.png");
+
+    #[test]
+    fn loads_jpg() {
+        let image = Image::load(CONTAINER, 0).unwrap();
+        assert_eq!((image.width(), image.height()), (512, 512));
+        assert_eq!(image.channels(), 3);
+        assert_eq!(image.channels_in_file(), 3);
+        assert_eq!(image.as_slice().len(), 512 * 512 * 3);
+    }
+
+    #[test]
+    fn loads_png_from_memory_like_from_file() {
+        let bytes = fs::read(FACE).unwrap();
+        let from_memory = Image::from_memory(&bytes, 0).unwrap();
+        let from_file = Image::load(FACE, 0).unwrap();
+        assert_eq!(from_memory.channels(), 4);
+        assert_eq!((from_memory.width(), from_memory.height()), (from_file.width(), from_file.height()));
+        assert!(from_memory.as_slice() == from_file.as_slice());
+    }
+
+    #[test]
+    fn info_matches_load() {
+        assert_eq!(info(CONTAINER).unwrap(), Info { width: 512, height: 512, channels: 3 });
+        let bytes = fs::read(FACE).unwrap();
+        let image = Image::load(FACE, 0).unwrap();
+        assert_eq!(info_from_memory(&bytes).unwrap(), Info { width: image.width(), height: image.height(), channels: 4 });
+    }
+
+    #[test]
+    fn ldr_files_are_not_hdr() {
+        assert!(!is_hdr(CONTAINER).unwrap());
+        assert!(!is_hdr(FACE).unwrap());
+    }
+
+    #[test]
+    fn forces_channel_count() {
+        let rgba = Image::load(CONTAINER, 4).unwrap();
+        assert_eq!(rgba.channels(), 4);
+        assert_eq!(rgba.channels_in_file(), 3);
+        assert!(rgba.as_slice().chunks(4).all(|pixel| pixel[3] == 255));
+
+        let rgb = Image::load(CONTAINER, 3).unwrap();
+        let same_colors = rgba.as_slice().chunks(4).zip(rgb.as_slice().chunks(3)).all(|(a, b)| a[..3] == *b);
+        assert!(same_colors);
+
+        let grey = Image::load(FACE, 1).unwrap();
+        assert_eq!(grey.channels(), 1);
+        assert_eq!(grey.as_slice().len(), (grey.width() * grey.height()) as usize);
+
+        assert_eq!(Image::load(FACE, 5).unwrap_err(), Error::InvalidChannels(5));
+        assert_eq!(Image::load(FACE, -1).unwrap_err(), Error::InvalidChannels(-1));
+    }
+
+    #[test]
+    fn flips_rows() {
+        let image = Image::load(CONTAINER, 0).unwrap();
+        let flipped = LoadOptions { flip_vertically: true, ..Default::default() }.load(CONTAINER).unwrap();
+        let row_len = (image.width() * image.channels()) as usize;
+        let rows: Vec<&[u8]> = image.as_slice().chunks(row_len).collect();
+        let flipped_rows: Vec<&[u8]> = flipped.as_slice().chunks(row_len).rev().collect();
+        assert!(rows == flipped_rows);
+    }
+
+    #[test]
+    fn premultiplies_alpha() {
+        let options = LoadOptions { premultiply_alpha: true, ..Default::default() };
+        let image = Image::load(FACE, 0).unwrap();
+        let premultiplied = options.load(FACE).unwrap();
+        for (straight, pixel) in image.as_slice().chunks(4).zip(premultiplied.as_slice().chunks(4)) {
+            let alpha = straight[3];
+            assert_eq!(pixel[3], alpha);
+            for c in 0..3 {
+                assert_eq!(pixel[c], straight[c].premultiply(alpha));
+            }
+        }
+
+        let pixels = [200, 100, 50, 255, 200, 100, 50, 128, 200, 100, 50, 0];
+        let png = ::png::encode_png(3, 1, 4, &pixels).unwrap();
+        let translucent = options.load_from_memory(&png).unwrap();
+        assert_eq!(translucent.as_slice(), &[200, 100, 50, 255, 100, 50, 25, 128, 0, 0, 0, 0]);
+
+        // Only images with alpha are touched
+        let opaque = options.load(CONTAINER).unwrap();
+        assert!(opaque.as_slice() == Image::load(CONTAINER, 0).unwrap().as_slice());
+    }
+
+    #[test]
+    fn premultiplies_components() {
+        assert_eq!(200u8.premultiply(255), 200);
+        assert_eq!(200u8.premultiply(0), 0);
+        assert_eq!(255u8.premultiply(128), 128);
+        assert_eq!(65535u16.premultiply(32768), 32768);
+        assert_eq!(0.5f32.premultiply(0.5), 0.25);
+    }
+
+    #[test]
+    fn rejects_bogus_data() {
+        let bogus = b"definitely not an image";
+        match Image::from_memory(bogus, 0) {
+            Err(Error::Decode(_)) => {},
+            other => panic!("expected a decode error, got {:?}", other),
+        }
+        match info_from_memory(bogus) {
+            Err(Error::Decode(_)) => {},
+            other => panic!("expected a decode error, got {:?}", other),
+        }
+        match Image::from_memory(&[], 0) {
+            Err(Error::Decode(_)) => {},
+            other => panic!("expected a decode error, got {:?}", other),
+        }
+        assert!(Image::load("Resources/missing.png", 0).is_err());
+        assert_eq!(Image::load("nul\0byte.png", 0).unwrap_err(), Error::InvalidPath("nul\0byte.png".to_string()));
+    }
+
+    #[test]
+    fn failure_reasons_are_per_thread() {
+        // A PNG signature followed by the wrong first chunk fails differently from garbage
+        let mut bad_png = b"\x89PNG\r\n\x1a\n".to_vec();
+        bad_png.extend_from_slice(b"\0\0\0\x04IDAT\0\0\0\0\0\0\0\0");
+        let reason = |bytes: &[u8]| match Image::from_memory(bytes, 0) {
+            Err(Error::Decode(reason)) => reason,
+            other => panic!("expected a decode error, got {:?}", other),
+        };
+        let garbage_reason = reason(b"definitely not an image");
+        let png_reason = reason(&bad_png);
+        assert_ne!(garbage_reason, png_reason);
+
+        // Both threads fail at the same time over and over
+        let start = std::sync::Arc::new(std::sync::Barrier::new(2));
+        let threads: Vec<_> = vec![(b"definitely not an image".to_vec(), garbage_reason), (bad_png, png_reason)]
+            .into_iter()
+            .map(|(bytes, expected)| {
+                let start = start.clone();
+                std::thread::spawn(move || {
+                    start.wait();
+                    for _ in 0..100000 {
+                        assert_eq!(reason(&bytes), expected);
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    }
+}
@@ -7,4 +7,12 @@
 
 // For autocompletion I use the file here
 pub use bindings::*;
-mod bindings;
\ No newline at end of file
+mod bindings;
+
+// Safe wrappers, prefer these over the raw functions
+pub use image::{Image, Info, Error, LoadOptions, Component, info, info_from_memory, is_hdr, failure_reason};
+mod image;
+
+// Writing, in Rust so it doesn't need stb_image_write
+pub use png::{encode_png, write_png};
+mod png;
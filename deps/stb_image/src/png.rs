@@ -0,0 +1,184 @@
+use std::fs;
+
+use image::Error;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+// Largest block deflate can store uncompressed
+const MAX_STORED_BLOCK: usize = 65535;
+
+// Encodes 8 bit pixels, top row first, as a PNG. The pixel data is stored
+// without compression, so files are about as big as the pixels themselves
+// but nothing beyond crc and adler checksums is needed to write them.
+pub fn encode_png(width: u32, height: u32, channels: i32, pixels: &[u8]) -> Result<Vec<u8>, Error> {
+    let color_type = match channels {
+        1 => 0, // grey
+        2 => 4, // grey + alpha
+        3 => 2, // rgb
+        4 => 6, // rgba
+        n => return Err(Error::InvalidChannels(n)),
+    };
+    let stride = width as usize * channels as usize;
+    if pixels.len() != stride * height as usize {
+        return Err(Error::InvalidSize { expected: stride * height as usize, actual: pixels.len() });
+    }
+
+    // Every row starts with its filter type, 0 is none
+    let mut raw = Vec::with_capacity((stride + 1) * height as usize);
+    for row in pixels.chunks(stride.max(1)).take(height as usize) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+
+    let mut png = Vec::with_capacity(raw.len() + raw.len() / MAX_STORED_BLOCK * 5 + 64);
+    png.extend_from_slice(&SIGNATURE);
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // Bit depth, color type, compression, filter and interlace methods
+    header.extend_from_slice(&[8, color_type, 0, 0, 0]);
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+    write_chunk(&mut png, b"IEND", &[]);
+    Ok(png)
+}
+
+pub fn write_png(path: &str, width: u32, height: u32, channels: i32, pixels: &[u8]) -> Result<(), Error> {
+    let png = encode_png(width, height, channels, pixels)?;
+    fs::write(path, png).map_err(|err| Error::Write(format!("{}: {}", path, err)))
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+// A zlib stream of stored deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + data.len() / MAX_STORED_BLOCK * 5 + 11);
+    // Deflate with a 32K window, no preset dictionary, check bits for 0x7801
+    out.extend_from_slice(&[0x78, 0x01]);
+    let mut blocks = data.chunks(MAX_STORED_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        // An empty final block
+        out.extend_from_slice(&[1, 0x00, 0x00, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut table = [0u32; 256];
+    for (n, entry) in table.iter_mut().enumerate() {
+        let mut c = n as u32;
+        for _ in 0..8 {
+            c = if c & 1 != 0 { 0xEDB8_8320 ^ (c >> 1) } else { c >> 1 };
+        }
+        *entry = c;
+    }
+    !data.iter().fold(!0u32, |crc, &byte| table[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8))
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    // 5552 bytes is the most that can be summed before b could overflow
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use image::Image;
+
+    fn gradient(width: u32, height: u32, channels: i32) -> Vec<u8> {
+        (0..width * height * channels as u32).map(|i| (i * 7 + i / 13) as u8).collect()
+    }
+
+    fn read_u32(bytes: &[u8]) -> u32 {
+        u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+
+    #[test]
+    fn checksums_match_known_values() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(adler32(b""), 1);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn adler32_reduces_long_input() {
+        let data = vec![0xFF; 100_000];
+        let (mut a, mut b) = (1u64, 0u64);
+        for &byte in data.iter() {
+            a = (a + byte as u64) % 65521;
+            b = (b + a) % 65521;
+        }
+        assert_eq!(adler32(&data), ((b << 16) | a) as u32);
+    }
+
+    #[test]
+    fn round_trips_every_channel_count() {
+        for channels in 1..5 {
+            let pixels = gradient(5, 3, channels);
+            let png = encode_png(5, 3, channels, &pixels).unwrap();
+            let image = Image::from_memory(&png, 0).unwrap();
+            assert_eq!((image.width(), image.height(), image.channels()), (5, 3, channels));
+            assert_eq!(image.as_slice(), &pixels[..]);
+        }
+    }
+
+    #[test]
+    fn round_trips_over_several_stored_blocks() {
+        // 120000 bytes of rows, two stored blocks
+        let pixels = gradient(200, 150, 4);
+        let png = encode_png(200, 150, 4, &pixels).unwrap();
+        let image = Image::from_memory(&png, 0).unwrap();
+        assert!(image.as_slice() == &pixels[..]);
+    }
+
+    #[test]
+    fn chunks_carry_their_crc() {
+        let png = encode_png(3, 2, 3, &gradient(3, 2, 3)).unwrap();
+        assert_eq!(&png[..8], &SIGNATURE);
+        let mut offset = 8;
+        let mut kinds = Vec::new();
+        while offset < png.len() {
+            let length = read_u32(&png[offset..]) as usize;
+            let body = &png[offset + 4..offset + 8 + length];
+            assert_eq!(read_u32(&png[offset + 8 + length..]), crc32(body));
+            kinds.push(String::from_utf8_lossy(&body[..4]).into_owned());
+            offset += 12 + length;
+        }
+        assert_eq!(kinds, vec!["IHDR", "IDAT", "IEND"]);
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert_eq!(encode_png(2, 2, 5, &[0; 20]).unwrap_err(), Error::InvalidChannels(5));
+        assert_eq!(encode_png(2, 2, 3, &[0; 11]).unwrap_err(), Error::InvalidSize { expected: 12, actual: 11 });
+    }
+}
@@ -1,2 +1,8 @@
 #define STB_IMAGE_IMPLEMENTATION
+// Images are decoded on worker threads, each needs its own stbi_failure_reason
+#if defined(_MSC_VER)
+#define STBI_THREAD_LOCAL __declspec(thread)
+#else
+#define STBI_THREAD_LOCAL _Thread_local
+#endif
 #include "stb_image.h"
\ No newline at end of file
@@ -61,6 +61,10 @@ impl Camera {
         self.zoom
     }
 
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom.max(1.0).min(ZOOM);
+    }
+
     pub fn get_position(&self) -> Vector3<f32> {
         return self.position;
     }
@@ -0,0 +1,79 @@
+#pragma once
+
+// The limits are injected from light.rs, these are only fallbacks
+#ifndef MAX_DIR_LIGHTS
+#define MAX_DIR_LIGHTS 4
+#endif
+#ifndef MAX_POINT_LIGHTS
+#define MAX_POINT_LIGHTS 16
+#endif
+#ifndef MAX_SPOT_LIGHTS
+#define MAX_SPOT_LIGHTS 8
+#endif
+
+// Each map replaces the matching constant color when its define
+// (DIFFUSE_MAP, SPECULAR_MAP, NORMAL_MAP or EMISSION_MAP) is set
+struct Material {
+    vec3 ambient;
+    vec3 diffuse;
+    vec3 specular;
+    vec3 emission;
+    float shininess;
+
+#ifdef DIFFUSE_MAP
+    sampler2D diffuseMap;
+#endif
+#ifdef SPECULAR_MAP
+    sampler2D specularMap;
+#endif
+#ifdef NORMAL_MAP
+    sampler2D normalMap;
+#endif
+#ifdef EMISSION_MAP
+    sampler2D emissionMap;
+#endif
+};
+uniform Material material;
+
+struct DirLight {
+    vec3 direction;
+
+    vec3 ambient;
+    vec3 diffuse;
+    vec3 specular;
+};
+
+struct PointLight {
+    vec3 position;
+
+    float constant;
+    float linear;
+    float quadratic;
+
+    vec3 ambient;
+    vec3 diffuse;
+    vec3 specular;
+};
+
+struct SpotLight {
+    vec3 position;
+    vec3 direction;
+    // Cosines of the cone angles
+    float cutOff;
+    float outerCutOff;
+
+    float constant;
+    float linear;
+    float quadratic;
+
+    vec3 ambient;
+    vec3 diffuse;
+    vec3 specular;
+};
+
+uniform DirLight dirLights[MAX_DIR_LIGHTS];
+uniform PointLight pointLights[MAX_POINT_LIGHTS];
+uniform SpotLight spotLights[MAX_SPOT_LIGHTS];
+uniform int nrDirLights;
+uniform int nrPointLights;
+uniform int nrSpotLights;
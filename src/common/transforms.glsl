@@ -0,0 +1,5 @@
+#pragma once
+
+uniform mat4 model;
+uniform mat4 view;
+uniform mat4 projection;
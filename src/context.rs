@@ -0,0 +1,153 @@
+// Where the gl context comes from. The window opens an SDL window, headless
+// contexts render into an offscreen framebuffer so the scene can run on
+// machines without a display or a GPU.
+use gl;
+use sdl2;
+use std::os::raw::c_void;
+
+use render_gl::{ColorFormat, DepthFormat, DepthStorage, Framebuffer};
+
+#[cfg(feature = "headless-egl")]
+mod egl;
+#[cfg(feature = "headless-osmesa")]
+mod osmesa;
+
+pub trait Context {
+    fn get_proc_address(&self, name: &str) -> *const c_void;
+
+    // Size of the framebuffer the scene draws into
+    fn size(&self) -> (u32, u32);
+
+    // Shows the frame, or just waits for it when there's nowhere to show it
+    fn swap_buffers(&self);
+
+    // Loads gl, the context has to be current
+    fn load_gl(&self) {
+        gl::load_with(|s| self.get_proc_address(s));
+    }
+}
+
+// What the gl bindings were generated for, core profile or GLES, with the
+// debug flag when built with debug-output
+pub const GL_VERSION: (u8, u8) = gl::TARGET_VERSION;
+
+pub struct WindowContext {
+    pub sdl: sdl2::Sdl,
+    pub video: sdl2::VideoSubsystem,
+    pub window: sdl2::video::Window,
+    _gl_context: sdl2::video::GLContext,
+}
+
+impl WindowContext {
+    pub fn new(title: &str, width: u32, height: u32) -> Result<Self, String> {
+        let sdl = sdl2::init()?;
+        let video = sdl.video()?;
+
+        let gl_attr = video.gl_attr();
+        gl_attr.set_context_profile(if gl::TARGET_GLES { sdl2::video::GLProfile::GLES } else { sdl2::video::GLProfile::Core });
+        gl_attr.set_context_version(GL_VERSION.0, GL_VERSION.1);
+        if cfg!(feature = "debug-output") {
+            gl_attr.set_context_flags().debug().set();
+        }
+
+        let window = video
+            .window(title, width, height)
+            .opengl()
+            .resizable()
+            .build()
+            .map_err(|err| err.to_string())?;
+        let gl_context = window.gl_create_context()?;
+
+        let context = WindowContext { sdl, video, window, _gl_context: gl_context };
+        context.load_gl();
+        Ok(context)
+    }
+}
+
+impl Context for WindowContext {
+    fn get_proc_address(&self, name: &str) -> *const c_void {
+        self.video.gl_get_proc_address(name) as *const c_void
+    }
+
+    fn size(&self) -> (u32, u32) {
+        self.window.drawable_size()
+    }
+
+    fn swap_buffers(&self) {
+        self.window.gl_swap_window();
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeadlessBackend {
+    // EGL_MESA_platform_surfaceless, a GPU if there is one, llvmpipe otherwise
+    Egl,
+    // Mesa's software renderer, no display or device needed at all
+    OsMesa,
+}
+
+impl HeadlessBackend {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "egl" => Some(HeadlessBackend::Egl),
+            "osmesa" => Some(HeadlessBackend::OsMesa),
+            _ => None,
+        }
+    }
+}
+
+// Renders into a framebuffer with a color texture and a depth/stencil
+// renderbuffer, which stays bound as the draw and read framebuffer
+pub struct HeadlessContext {
+    width: u32,
+    height: u32,
+    framebuffer: Framebuffer,
+    // Dropped after the framebuffer is deleted
+    platform: Box<dyn Context>,
+}
+
+impl HeadlessContext {
+    pub fn new(backend: HeadlessBackend, width: u32, height: u32) -> Result<Self, String> {
+        let platform = create_platform(backend, width, height)?;
+        platform.load_gl();
+
+        let mut framebuffer = Framebuffer::builder(width, height)
+            .color(ColorFormat::Rgba8)
+            .depth(DepthFormat::Depth24Stencil8, DepthStorage::Renderbuffer)
+            .build()
+            .map_err(|err| format!("Offscreen framebuffer: {}", err))?;
+        framebuffer.set_label("headless");
+        framebuffer.bind();
+        Ok(HeadlessContext { width, height, framebuffer, platform })
+    }
+
+    pub fn framebuffer(&self) -> &Framebuffer {
+        &self.framebuffer
+    }
+}
+
+impl Context for HeadlessContext {
+    fn get_proc_address(&self, name: &str) -> *const c_void {
+        self.platform.get_proc_address(name)
+    }
+
+    fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn swap_buffers(&self) {
+        self.platform.swap_buffers();
+    }
+}
+
+fn create_platform(backend: HeadlessBackend, width: u32, height: u32) -> Result<Box<dyn Context>, String> {
+    match backend {
+        #[cfg(feature = "headless-egl")]
+        HeadlessBackend::Egl => egl::EglContext::new(width, height).map(|context| Box::new(context) as Box<dyn Context>),
+        #[cfg(feature = "headless-osmesa")]
+        HeadlessBackend::OsMesa => osmesa::OsMesaContext::new(width, height).map(|context| Box::new(context) as Box<dyn Context>),
+        #[allow(unreachable_patterns)]
+        _ => Err(format!("{:?} isn't available, build with --features headless-{}", backend,
+            if backend == HeadlessBackend::Egl { "egl" } else { "osmesa" })),
+    }
+}
@@ -0,0 +1,152 @@
+// A surfaceless EGL context from Mesa, there's no default framebuffer so
+// HeadlessContext draws into its own.
+#![allow(non_camel_case_types)]
+
+use gl;
+use std;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+
+use super::{Context, GL_VERSION};
+
+type EGLDisplay = *mut c_void;
+type EGLConfig = *mut c_void;
+type EGLContext = *mut c_void;
+type EGLSurface = *mut c_void;
+type EGLBoolean = u32;
+type EGLint = i32;
+type EGLenum = u32;
+
+const EGL_NONE: EGLint = 0x3038;
+const EGL_TRUE: EGLint = 1;
+const EGL_EXTENSIONS: EGLint = 0x3055;
+const EGL_SURFACE_TYPE: EGLint = 0x3033;
+const EGL_PBUFFER_BIT: EGLint = 0x0001;
+const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
+const EGL_OPENGL_BIT: EGLint = 0x0008;
+const EGL_OPENGL_ES3_BIT: EGLint = 0x0040;
+const EGL_OPENGL_API: EGLenum = 0x30A2;
+const EGL_OPENGL_ES_API: EGLenum = 0x30A0;
+const EGL_CONTEXT_MAJOR_VERSION: EGLint = 0x3098;
+const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x30FB;
+const EGL_CONTEXT_OPENGL_PROFILE_MASK: EGLint = 0x30FD;
+const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint = 0x0001;
+const EGL_CONTEXT_OPENGL_DEBUG: EGLint = 0x31B0;
+const EGL_PLATFORM_SURFACELESS_MESA: EGLenum = 0x31DD;
+
+type GetPlatformDisplayEXT = extern "system" fn(EGLenum, *mut c_void, *const EGLint) -> EGLDisplay;
+
+#[link(name = "EGL")]
+extern "system" {
+    fn eglGetProcAddress(procname: *const c_char) -> *const c_void;
+    fn eglGetError() -> EGLint;
+    fn eglQueryString(display: EGLDisplay, name: EGLint) -> *const c_char;
+    fn eglInitialize(display: EGLDisplay, major: *mut EGLint, minor: *mut EGLint) -> EGLBoolean;
+    fn eglTerminate(display: EGLDisplay) -> EGLBoolean;
+    fn eglBindAPI(api: EGLenum) -> EGLBoolean;
+    fn eglChooseConfig(display: EGLDisplay, attribs: *const EGLint, configs: *mut EGLConfig, size: EGLint, count: *mut EGLint) -> EGLBoolean;
+    fn eglCreateContext(display: EGLDisplay, config: EGLConfig, share: EGLContext, attribs: *const EGLint) -> EGLContext;
+    fn eglDestroyContext(display: EGLDisplay, context: EGLContext) -> EGLBoolean;
+    fn eglMakeCurrent(display: EGLDisplay, draw: EGLSurface, read: EGLSurface, context: EGLContext) -> EGLBoolean;
+}
+
+pub struct EglContext {
+    display: EGLDisplay,
+    context: EGLContext,
+    width: u32,
+    height: u32,
+}
+
+impl EglContext {
+    pub fn new(width: u32, height: u32) -> Result<Self, String> {
+        unsafe {
+            // Client extensions are queried without a display
+            let extensions = eglQueryString(std::ptr::null_mut(), EGL_EXTENSIONS);
+            let extensions = if extensions.is_null() { "" } else { CStr::from_ptr(extensions).to_str().unwrap_or("") };
+            if !extensions.split(' ').any(|extension| extension == "EGL_MESA_platform_surfaceless") {
+                return Err(String::from("EGL doesn't support EGL_MESA_platform_surfaceless"));
+            }
+            let get_platform_display = eglGetProcAddress(b"eglGetPlatformDisplayEXT\0".as_ptr() as *const c_char);
+            if get_platform_display.is_null() {
+                return Err(String::from("EGL doesn't have eglGetPlatformDisplayEXT"));
+            }
+            let get_platform_display: GetPlatformDisplayEXT = std::mem::transmute(get_platform_display);
+
+            let display = get_platform_display(EGL_PLATFORM_SURFACELESS_MESA, std::ptr::null_mut(), std::ptr::null());
+            let (mut major, mut minor) = (0, 0);
+            if display.is_null() || eglInitialize(display, &mut major, &mut minor) == 0 {
+                return Err(error("eglInitialize"));
+            }
+            let (api, renderable) = if gl::TARGET_GLES { (EGL_OPENGL_ES_API, EGL_OPENGL_ES3_BIT) } else { (EGL_OPENGL_API, EGL_OPENGL_BIT) };
+            if eglBindAPI(api) == 0 {
+                eglTerminate(display);
+                return Err(error("eglBindAPI"));
+            }
+
+            // The default surface type is window, which surfaceless displays don't have
+            let config_attribs = [EGL_SURFACE_TYPE, EGL_PBUFFER_BIT, EGL_RENDERABLE_TYPE, renderable, EGL_NONE];
+            let mut config = std::ptr::null_mut();
+            let mut count = 0;
+            if eglChooseConfig(display, config_attribs.as_ptr(), &mut config, 1, &mut count) == 0 || count == 0 {
+                eglTerminate(display);
+                return Err(error("eglChooseConfig"));
+            }
+
+            let mut context_attribs = vec![
+                EGL_CONTEXT_MAJOR_VERSION, GL_VERSION.0 as EGLint,
+                EGL_CONTEXT_MINOR_VERSION, GL_VERSION.1 as EGLint,
+            ];
+            // GLES has no profiles
+            if !gl::TARGET_GLES {
+                context_attribs.extend_from_slice(&[EGL_CONTEXT_OPENGL_PROFILE_MASK, EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT]);
+            }
+            if cfg!(feature = "debug-output") {
+                context_attribs.extend_from_slice(&[EGL_CONTEXT_OPENGL_DEBUG, EGL_TRUE]);
+            }
+            context_attribs.push(EGL_NONE);
+            let context = eglCreateContext(display, config, std::ptr::null_mut(), context_attribs.as_ptr());
+            if context.is_null() {
+                eglTerminate(display);
+                return Err(error("eglCreateContext"));
+            }
+            if eglMakeCurrent(display, std::ptr::null_mut(), std::ptr::null_mut(), context) == 0 {
+                eglDestroyContext(display, context);
+                eglTerminate(display);
+                return Err(error("eglMakeCurrent"));
+            }
+
+            Ok(EglContext { display, context, width, height })
+        }
+    }
+}
+
+impl Context for EglContext {
+    fn get_proc_address(&self, name: &str) -> *const c_void {
+        let name = CString::new(name).unwrap();
+        unsafe { eglGetProcAddress(name.as_ptr()) }
+    }
+
+    fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn swap_buffers(&self) {
+        unsafe {
+            gl::Finish();
+        }
+    }
+}
+
+impl Drop for EglContext {
+    fn drop(&mut self) {
+        unsafe {
+            eglMakeCurrent(self.display, std::ptr::null_mut(), std::ptr::null_mut(), std::ptr::null_mut());
+            eglDestroyContext(self.display, self.context);
+            eglTerminate(self.display);
+        }
+    }
+}
+
+fn error(call: &str) -> String {
+    format!("{} failed (EGL error 0x{:X})", call, unsafe { eglGetError() })
+}
@@ -0,0 +1,97 @@
+// Mesa's off-screen interface, renders with llvmpipe into memory we own.
+// HeadlessContext still draws into its own framebuffer so both headless
+// backends behave the same.
+
+use gl;
+use std;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+
+use super::{Context, GL_VERSION};
+
+type OSMesaContextHandle = *mut c_void;
+
+const OSMESA_FORMAT: c_int = 0x22;
+const OSMESA_DEPTH_BITS: c_int = 0x30;
+const OSMESA_STENCIL_BITS: c_int = 0x31;
+const OSMESA_PROFILE: c_int = 0x33;
+const OSMESA_CORE_PROFILE: c_int = 0x34;
+const OSMESA_CONTEXT_MAJOR_VERSION: c_int = 0x36;
+const OSMESA_CONTEXT_MINOR_VERSION: c_int = 0x37;
+
+#[link(name = "OSMesa")]
+extern "C" {
+    fn OSMesaCreateContextAttribs(attribs: *const c_int, share: OSMesaContextHandle) -> OSMesaContextHandle;
+    fn OSMesaDestroyContext(context: OSMesaContextHandle);
+    fn OSMesaMakeCurrent(context: OSMesaContextHandle, buffer: *mut c_void, kind: gl::types::GLenum, width: c_int, height: c_int) -> gl::types::GLboolean;
+    fn OSMesaGetProcAddress(name: *const c_char) -> *const c_void;
+}
+
+pub struct OsMesaContext {
+    context: OSMesaContextHandle,
+    // The default framebuffer, has to outlive the context
+    buffer: Vec<u8>,
+    width: u32,
+    height: u32,
+}
+
+impl OsMesaContext {
+    pub fn new(width: u32, height: u32) -> Result<Self, String> {
+        if gl::TARGET_GLES {
+            return Err(String::from("OSMesa can't create GLES contexts, use --headless egl"));
+        }
+        let attribs = [
+            OSMESA_FORMAT, gl::RGBA as c_int,
+            OSMESA_DEPTH_BITS, 24,
+            OSMESA_STENCIL_BITS, 8,
+            OSMESA_PROFILE, OSMESA_CORE_PROFILE,
+            OSMESA_CONTEXT_MAJOR_VERSION, GL_VERSION.0 as c_int,
+            OSMESA_CONTEXT_MINOR_VERSION, GL_VERSION.1 as c_int,
+            0,
+        ];
+        let context = unsafe { OSMesaCreateContextAttribs(attribs.as_ptr(), std::ptr::null_mut()) };
+        if context.is_null() {
+            return Err(format!("Couldn't create a {}.{} core OSMesa context", GL_VERSION.0, GL_VERSION.1));
+        }
+
+        let mut context = OsMesaContext { context, buffer: vec![0; (width * height * 4) as usize], width, height };
+        let current = unsafe {
+            OSMesaMakeCurrent(
+                context.context,
+                context.buffer.as_mut_ptr() as *mut c_void,
+                gl::UNSIGNED_BYTE,
+                width as c_int,
+                height as c_int,
+            )
+        };
+        if current == gl::FALSE {
+            return Err(String::from("OSMesaMakeCurrent failed"));
+        }
+        Ok(context)
+    }
+}
+
+impl Context for OsMesaContext {
+    fn get_proc_address(&self, name: &str) -> *const c_void {
+        let name = CString::new(name).unwrap();
+        unsafe { OSMesaGetProcAddress(name.as_ptr()) }
+    }
+
+    fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn swap_buffers(&self) {
+        unsafe {
+            gl::Finish();
+        }
+    }
+}
+
+impl Drop for OsMesaContext {
+    fn drop(&mut self) {
+        unsafe {
+            OSMesaDestroyContext(self.context);
+        }
+    }
+}
@@ -0,0 +1,1089 @@
+use std;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use cgmath::{Vector3, Vector4, Matrix4, Quaternion, InnerSpace, SquareMatrix, Zero};
+use serde_json::Value;
+
+use camera::Camera;
+use material::Material;
+use model::Model;
+use texture::{Texture2D, Texture2DBuilder, Wrap, Filter};
+
+const GLB_MAGIC: u32 = 0x4654_6C67; // "glTF"
+const GLB_CHUNK_JSON: u32 = 0x4E4F_534A;
+const GLB_CHUNK_BIN: u32 = 0x004E_4942;
+
+const MODE_TRIANGLES: u32 = 4;
+const MODE_TRIANGLE_STRIP: u32 = 5;
+const MODE_TRIANGLE_FAN: u32 = 6;
+
+// Everything decoded from a gltf file, no gl calls are made to produce this
+#[derive(Clone, Debug)]
+pub struct GltfData {
+    pub meshes: Vec<MeshData>,
+    pub materials: Vec<PbrMaterial>,
+    pub textures: Vec<TextureData>,
+    pub images: Vec<ImageData>,
+    pub cameras: Vec<CameraData>,
+    pub nodes: Vec<NodeData>,
+    pub scenes: Vec<Vec<usize>>,
+    pub default_scene: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MeshData {
+    pub name: String,
+    pub primitives: Vec<PrimitiveData>,
+}
+
+// A triangle list in the position + normal + uv layout of model::StandardVertex
+#[derive(Clone, Debug)]
+pub struct PrimitiveData {
+    pub vertices: Vec<f32>,
+    pub indices: Vec<u32>,
+    pub material: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PbrMaterial {
+    pub name: String,
+    pub base_color_factor: Vector4<f32>,
+    pub base_color_texture: Option<usize>,
+    pub metallic_factor: f32,
+    pub roughness_factor: f32,
+    pub metallic_roughness_texture: Option<usize>,
+    pub normal_texture: Option<usize>,
+    pub occlusion_texture: Option<usize>,
+    pub emissive_factor: Vector3<f32>,
+    pub emissive_texture: Option<usize>,
+}
+
+impl Default for PbrMaterial {
+    fn default() -> Self {
+        PbrMaterial {
+            name: String::from("default"),
+            base_color_factor: Vector4::new(1.0, 1.0, 1.0, 1.0),
+            base_color_texture: None,
+            metallic_factor: 1.0,
+            roughness_factor: 1.0,
+            metallic_roughness_texture: None,
+            normal_texture: None,
+            occlusion_texture: None,
+            emissive_factor: Vector3::zero(),
+            emissive_texture: None,
+        }
+    }
+}
+
+impl PbrMaterial {
+    // Rough approximation of the metallic-roughness model with the phong material used by light.frag.
+    // textures holds the uploaded gltf textures by index.
+    pub fn to_material(&self, textures: &[Option<Rc<Texture2D>>]) -> Material {
+        let base = self.base_color_factor.truncate();
+        let specular = Vector3::new(0.04, 0.04, 0.04) * (1.0 - self.metallic_factor) + base * self.metallic_factor;
+        let roughness = self.roughness_factor.max(0.05);
+        let map = |index: Option<usize>| index.and_then(|i| textures.get(i).cloned()).and_then(|t| t);
+        Material {
+            name: self.name.clone(),
+            ambient: base,
+            diffuse: base * (1.0 - self.metallic_factor),
+            specular: specular * (1.0 - roughness),
+            emission: self.emissive_factor,
+            shininess: (2.0 / roughness.powi(4) - 2.0).max(1.0),
+
+            diffuse_map: map(self.base_color_texture),
+            specular_map: None,
+            normal_map: map(self.normal_texture),
+            emission_map: map(self.emissive_texture),
+        }
+    }
+}
+
+// Sampler values are the gl enums gltf uses directly
+#[derive(Clone, Debug)]
+pub struct TextureData {
+    pub source: Option<usize>,
+    pub mag_filter: Option<u32>,
+    pub min_filter: Option<u32>,
+    pub wrap_s: u32,
+    pub wrap_t: u32,
+}
+
+#[derive(Clone, Debug)]
+pub enum ImageData {
+    File(PathBuf),
+    Memory { mime_type: Option<String>, data: Vec<u8> },
+}
+
+#[derive(Clone, Debug)]
+pub enum CameraData {
+    Perspective { yfov: f32, aspect_ratio: Option<f32>, znear: f32, zfar: Option<f32> },
+    Orthographic { xmag: f32, ymag: f32, znear: f32, zfar: f32 },
+}
+
+#[derive(Clone, Debug)]
+pub struct NodeData {
+    pub name: String,
+    pub transform: Matrix4<f32>,
+    pub children: Vec<usize>,
+    pub mesh: Option<usize>,
+    pub camera: Option<usize>,
+}
+
+// A mesh placed in the world by a node
+pub struct Instance {
+    pub node: usize,
+    pub mesh: usize,
+    pub transform: Matrix4<f32>,
+}
+
+// A gltf scene uploaded to gl
+pub struct Scene {
+    pub data: GltfData,
+    // Models per mesh, one for each primitive
+    pub meshes: Vec<Vec<Model>>,
+    pub instances: Vec<Instance>,
+    pub cameras: Vec<Camera>,
+}
+
+// Loads a .gltf or .glb file and uploads its meshes
+pub fn load(path: &str) -> Result<Scene, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("Couldn't read \"{}\": {}", path, e))?;
+    let base_dir = Path::new(path).parent().unwrap_or(Path::new(""));
+    let data = parse(&bytes, base_dir).map_err(|e| format!("{}: {}", path, e))?;
+
+    let textures: Vec<Option<Rc<Texture2D>>> = data.textures.iter().map(|t| {
+        match upload_texture(&data, t) {
+            Ok(texture) => Some(Rc::new(texture)),
+            Err(e) => {
+                println!("{}: {}", path, e);
+                None
+            }
+        }
+    }).collect();
+    let materials: Vec<Material> = data.materials.iter().map(|m| m.to_material(&textures)).collect();
+
+    let meshes = data.meshes.iter().map(|mesh| {
+        mesh.primitives.iter().map(|p| {
+            let material = match p.material {
+                Some(i) => materials[i].clone(),
+                None => PbrMaterial::default().to_material(&textures),
+            };
+            Model::new(p.vertices.clone(), p.indices.clone()).with_material(material)
+        }).collect()
+    }).collect();
+
+    let mut instances = Vec::new();
+    let mut cameras = Vec::new();
+    let roots = match data.default_scene.or(if data.scenes.is_empty() { None } else { Some(0) }) {
+        Some(scene) => data.scenes[scene].clone(),
+        None => root_nodes(&data.nodes),
+    };
+    for root in roots {
+        visit_node(&data, root, Matrix4::identity(), &mut instances, &mut cameras);
+    }
+
+    Ok(Scene { data, meshes, instances, cameras })
+}
+
+fn upload_texture(data: &GltfData, texture: &TextureData) -> Result<Texture2D, String> {
+    let wrap = |mode: u32| match mode {
+        ::gl::CLAMP_TO_EDGE => Wrap::ClampToEdge,
+        ::gl::MIRRORED_REPEAT => Wrap::MirroredRepeat,
+        _ => Wrap::Repeat,
+    };
+    let filter = |mode: Option<u32>| match mode {
+        Some(::gl::NEAREST) | Some(::gl::NEAREST_MIPMAP_NEAREST) | Some(::gl::NEAREST_MIPMAP_LINEAR) => Filter::Nearest,
+        _ => Filter::Linear,
+    };
+    let mipmaps = !matches!(texture.min_filter, Some(::gl::NEAREST) | Some(::gl::LINEAR));
+    // Gltf uvs start from the top left, which is already the first row of the image
+    let builder = Texture2DBuilder::new()
+        .wrap_st(wrap(texture.wrap_s), wrap(texture.wrap_t))
+        .filter(filter(texture.min_filter), filter(texture.mag_filter))
+        .mipmaps(mipmaps)
+        .flip_vertically(false);
+
+    let image = texture.source.and_then(|s| data.images.get(s)).ok_or_else(|| String::from("texture has no image"))?;
+    let result = match *image {
+        ImageData::File(ref path) => builder.load(&path.to_string_lossy()),
+        ImageData::Memory { ref data, .. } => builder.load_from_memory(data),
+    };
+    result.map_err(|e| e.to_string())
+}
+
+fn visit_node(data: &GltfData, index: usize, parent: Matrix4<f32>,
+              instances: &mut Vec<Instance>, cameras: &mut Vec<Camera>) {
+    let node = &data.nodes[index];
+    let world = parent * node.transform;
+    if let Some(mesh) = node.mesh {
+        instances.push(Instance { node: index, mesh, transform: world });
+    }
+    if let Some(camera) = node.camera {
+        cameras.push(camera_from_node(&data.cameras[camera], world));
+    }
+    for &child in node.children.iter() {
+        visit_node(data, child, world, instances, cameras);
+    }
+}
+
+// Gltf cameras look down -Z of their node
+fn camera_from_node(camera: &CameraData, world: Matrix4<f32>) -> Camera {
+    let position = world.w.truncate();
+    let front = -world.z.truncate().normalize();
+    let yaw = front.z.atan2(front.x).to_degrees();
+    let pitch = front.y.clamp(-1.0, 1.0).asin().to_degrees();
+    let mut c = Camera::new(position, Vector3::new(0.0, 1.0, 0.0), yaw, pitch);
+    if let CameraData::Perspective { yfov, .. } = *camera {
+        c.set_zoom(yfov.to_degrees());
+    }
+    c
+}
+
+// Nodes nobody references as a child, used when the file has no scenes
+fn root_nodes(nodes: &[NodeData]) -> Vec<usize> {
+    (0..nodes.len())
+        .filter(|i| !nodes.iter().any(|n| n.children.contains(i)))
+        .collect()
+}
+
+// Parses either a binary .glb or a json .gltf. External buffers and images
+// are resolved relative to base_dir.
+pub fn parse(bytes: &[u8], base_dir: &Path) -> Result<GltfData, String> {
+    let (json, bin) = if bytes.len() >= 4 && read_u32(bytes, 0) == GLB_MAGIC {
+        split_glb(bytes)?
+    } else {
+        (bytes, None)
+    };
+    let root: Value = ::serde_json::from_slice(json).map_err(|e| format!("Invalid json: {}", e))?;
+
+    let buffers = load_buffers(&root, bin, base_dir)?;
+    let reader = AccessorReader { root: &root, buffers: &buffers };
+
+    let mut meshes = Vec::new();
+    for (i, mesh) in array(&root, "meshes").iter().enumerate() {
+        let mut primitives = Vec::new();
+        for primitive in array(mesh, "primitives").iter() {
+            if let Some(p) = reader.primitive(primitive).map_err(|e| format!("mesh {}: {}", i, e))? {
+                primitives.push(p);
+            }
+        }
+        meshes.push(MeshData { name: name(mesh, "mesh", i), primitives });
+    }
+
+    let materials = array(&root, "materials").iter().enumerate()
+        .map(|(i, m)| parse_material(m, i))
+        .collect();
+
+    let textures = array(&root, "textures").iter().map(|t| {
+        let sampler = get_usize(t, "sampler").and_then(|s| array(&root, "samplers").get(s));
+        let sampler_enum = |key: &str| sampler.and_then(|s| get_usize(s, key)).map(|v| v as u32);
+        TextureData {
+            source: get_usize(t, "source"),
+            mag_filter: sampler_enum("magFilter"),
+            min_filter: sampler_enum("minFilter"),
+            wrap_s: sampler_enum("wrapS").unwrap_or(::gl::REPEAT),
+            wrap_t: sampler_enum("wrapT").unwrap_or(::gl::REPEAT),
+        }
+    }).collect();
+
+    let mut images = Vec::new();
+    for (i, image) in array(&root, "images").iter().enumerate() {
+        images.push(reader.image(image, base_dir).map_err(|e| format!("image {}: {}", i, e))?);
+    }
+
+    let mut cameras = Vec::new();
+    for (i, camera) in array(&root, "cameras").iter().enumerate() {
+        cameras.push(parse_camera(camera).map_err(|e| format!("camera {}: {}", i, e))?);
+    }
+
+    let mut nodes = Vec::new();
+    for (i, node) in array(&root, "nodes").iter().enumerate() {
+        nodes.push(NodeData {
+            name: name(node, "node", i),
+            transform: node_transform(node).map_err(|e| format!("node {}: {}", i, e))?,
+            children: array(node, "children").iter().filter_map(|c| c.as_u64()).map(|c| c as usize).collect(),
+            mesh: get_usize(node, "mesh"),
+            camera: get_usize(node, "camera"),
+        });
+    }
+
+    let scenes = array(&root, "scenes").iter().map(|s| {
+        array(s, "nodes").iter().filter_map(|n| n.as_u64()).map(|n| n as usize).collect()
+    }).collect();
+
+    let data = GltfData {
+        meshes,
+        materials,
+        textures,
+        images,
+        cameras,
+        nodes,
+        scenes,
+        default_scene: get_usize(&root, "scene"),
+    };
+    validate(&data)?;
+    Ok(data)
+}
+
+// Checks every index between top level objects so later code can index freely
+fn validate(data: &GltfData) -> Result<(), String> {
+    let check = |what: &str, index: usize, len: usize| {
+        if index < len { Ok(()) } else { Err(format!("{} index {} out of range", what, index)) }
+    };
+    for mesh in data.meshes.iter() {
+        for p in mesh.primitives.iter() {
+            if let Some(m) = p.material { check("material", m, data.materials.len())?; }
+        }
+    }
+    for texture in data.textures.iter() {
+        if let Some(s) = texture.source { check("image", s, data.images.len())?; }
+    }
+    let mut parents = vec![None; data.nodes.len()];
+    for (i, node) in data.nodes.iter().enumerate() {
+        if let Some(m) = node.mesh { check("mesh", m, data.meshes.len())?; }
+        if let Some(c) = node.camera { check("camera", c, data.cameras.len())?; }
+        for &c in node.children.iter() {
+            check("node", c, data.nodes.len())?;
+            if parents[c].replace(i).is_some() {
+                return Err(format!("node {} has more than one parent", c));
+            }
+        }
+    }
+    // With one parent each, walking up more steps than there are nodes means
+    // going around a cycle, which visit_node would follow forever
+    for start in 0..parents.len() {
+        let mut node = start;
+        for _ in 0..parents.len() {
+            match parents[node] {
+                Some(parent) => node = parent,
+                None => break,
+            }
+        }
+        if parents[node].is_some() {
+            return Err(format!("node {} has a cycle in its parents", start));
+        }
+    }
+    for scene in data.scenes.iter() {
+        for &n in scene.iter() { check("node", n, data.nodes.len())?; }
+    }
+    if let Some(s) = data.default_scene { check("scene", s, data.scenes.len())?; }
+    Ok(())
+}
+
+fn split_glb(bytes: &[u8]) -> Result<(&[u8], Option<&[u8]>), String> {
+    if bytes.len() < 12 {
+        return Err(String::from("Truncated glb header"));
+    }
+    let version = read_u32(bytes, 4);
+    if version != 2 {
+        return Err(format!("Unsupported glb version {}", version));
+    }
+    let length = (read_u32(bytes, 8) as usize).min(bytes.len());
+
+    let mut json = None;
+    let mut bin = None;
+    let mut offset = 12;
+    while offset + 8 <= length {
+        let chunk_length = read_u32(bytes, offset) as usize;
+        let chunk_type = read_u32(bytes, offset + 4);
+        let start = offset + 8;
+        let end = start + chunk_length;
+        if end > length {
+            return Err(String::from("Truncated glb chunk"));
+        }
+        match chunk_type {
+            GLB_CHUNK_JSON if json.is_none() => json = Some(&bytes[start..end]),
+            GLB_CHUNK_BIN if bin.is_none() => bin = Some(&bytes[start..end]),
+            // Unknown chunks must be ignored
+            _ => {}
+        }
+        // Chunks are 4 byte aligned
+        offset = end + (4 - chunk_length % 4) % 4;
+    }
+    match json {
+        Some(json) => Ok((json, bin)),
+        None => Err(String::from("glb has no json chunk")),
+    }
+}
+
+fn load_buffers(root: &Value, bin: Option<&[u8]>, base_dir: &Path) -> Result<Vec<Vec<u8>>, String> {
+    let mut buffers = Vec::new();
+    for (i, buffer) in array(root, "buffers").iter().enumerate() {
+        let byte_length = get_usize(buffer, "byteLength").unwrap_or(0);
+        let data = match buffer.get("uri").and_then(|u| u.as_str()) {
+            Some(uri) => load_uri(uri, base_dir).map_err(|e| format!("buffer {}: {}", i, e))?.1,
+            // Only the first buffer may refer to the glb binary chunk
+            None if i == 0 && bin.is_some() => bin.unwrap().to_vec(),
+            None => return Err(format!("buffer {} has no data", i)),
+        };
+        if data.len() < byte_length {
+            return Err(format!("buffer {} is {} bytes, expected {}", i, data.len(), byte_length));
+        }
+        buffers.push(data);
+    }
+    Ok(buffers)
+}
+
+// Returns the mime type of data uris along with the bytes
+fn load_uri(uri: &str, base_dir: &Path) -> Result<(Option<String>, Vec<u8>), String> {
+    if uri.starts_with("data:") {
+        let comma = uri.find(',').ok_or_else(|| String::from("Malformed data uri"))?;
+        let header = &uri[5..comma];
+        if !header.ends_with(";base64") {
+            return Err(String::from("Only base64 data uris are supported"));
+        }
+        let mime = &header[..header.len() - 7];
+        let mime = if mime.is_empty() { None } else { Some(mime.to_string()) };
+        return decode_base64(&uri[comma + 1..]).map(|data| (mime, data));
+    }
+    let path = base_dir.join(percent_decode(uri));
+    std::fs::read(&path)
+        .map(|data| (None, data))
+        .map_err(|e| format!("Couldn't read \"{}\": {}", path.display(), e))
+}
+
+struct AccessorReader<'a> {
+    root: &'a Value,
+    buffers: &'a [Vec<u8>],
+}
+
+impl<'a> AccessorReader<'a> {
+    fn primitive(&self, primitive: &Value) -> Result<Option<PrimitiveData>, String> {
+        let mode = get_usize(primitive, "mode").unwrap_or(MODE_TRIANGLES as usize) as u32;
+        if mode != MODE_TRIANGLES && mode != MODE_TRIANGLE_STRIP && mode != MODE_TRIANGLE_FAN {
+            println!("Skipping gltf primitive with unsupported mode {}", mode);
+            return Ok(None);
+        }
+        let attributes = primitive.get("attributes").ok_or_else(|| String::from("primitive has no attributes"))?;
+        let position_accessor = get_usize(attributes, "POSITION")
+            .ok_or_else(|| String::from("primitive has no POSITION"))?;
+
+        let positions = self.read_floats(position_accessor, 3)?;
+        let normals = match get_usize(attributes, "NORMAL") {
+            Some(a) => Some(self.read_floats(a, 3)?),
+            None => None,
+        };
+        let texcoords = match get_usize(attributes, "TEXCOORD_0") {
+            Some(a) => Some(self.read_floats(a, 2)?),
+            None => None,
+        };
+        let vertex_count = positions.len() / 3;
+        if normals.as_ref().is_some_and(|n| n.len() < vertex_count * 3)
+            || texcoords.as_ref().is_some_and(|t| t.len() < vertex_count * 2) {
+            return Err(String::from("attributes have fewer elements than POSITION"));
+        }
+
+        let indices = match get_usize(primitive, "indices") {
+            Some(a) => self.read_indices(a)?,
+            None => (0..vertex_count as u32).collect(),
+        };
+        if let Some(&bad) = indices.iter().find(|&&i| i as usize >= vertex_count) {
+            return Err(format!("index {} out of range", bad));
+        }
+        let indices = triangulate(&indices, mode);
+
+        let texcoord = |v: usize| match texcoords {
+            Some(ref t) => [t[v * 2], t[v * 2 + 1]],
+            None => [0.0, 0.0],
+        };
+
+        let (vertices, indices) = match normals {
+            Some(normals) => {
+                let mut vertices = Vec::with_capacity(vertex_count * 8);
+                for v in 0..vertex_count {
+                    vertices.extend_from_slice(&positions[v * 3..v * 3 + 3]);
+                    vertices.extend_from_slice(&normals[v * 3..v * 3 + 3]);
+                    vertices.extend_from_slice(&texcoord(v));
+                }
+                (vertices, indices)
+            },
+            // Spec says to use flat normals, which needs the vertices unwelded
+            None => flat_shaded(&positions, &texcoord, &indices),
+        };
+
+        Ok(Some(PrimitiveData {
+            vertices,
+            indices,
+            material: get_usize(primitive, "material"),
+        }))
+    }
+
+    fn image(&self, image: &Value, base_dir: &Path) -> Result<ImageData, String> {
+        let mime_type = image.get("mimeType").and_then(|m| m.as_str()).map(|m| m.to_string());
+        if let Some(uri) = image.get("uri").and_then(|u| u.as_str()) {
+            if uri.starts_with("data:") {
+                let (uri_mime, data) = load_uri(uri, base_dir)?;
+                return Ok(ImageData::Memory { mime_type: mime_type.or(uri_mime), data });
+            }
+            return Ok(ImageData::File(base_dir.join(percent_decode(uri))));
+        }
+        let view = get_usize(image, "bufferView").ok_or_else(|| String::from("image has no uri or bufferView"))?;
+        let (bytes, _) = self.buffer_view(view)?;
+        Ok(ImageData::Memory { mime_type, data: bytes.to_vec() })
+    }
+
+    fn accessor(&self, index: usize) -> Result<&'a Value, String> {
+        array(self.root, "accessors").get(index).ok_or_else(|| format!("accessor {} out of range", index))
+    }
+
+    // Returns the bytes of a buffer view and its stride, if any
+    fn buffer_view(&self, index: usize) -> Result<(&'a [u8], Option<usize>), String> {
+        let view = array(self.root, "bufferViews").get(index)
+            .ok_or_else(|| format!("bufferView {} out of range", index))?;
+        let buffer = get_usize(view, "buffer")
+            .and_then(|b| self.buffers.get(b))
+            .ok_or_else(|| format!("bufferView {} has an invalid buffer", index))?;
+        let offset = get_usize(view, "byteOffset").unwrap_or(0);
+        let length = get_usize(view, "byteLength").unwrap_or(0);
+        let bytes = offset.checked_add(length).and_then(|end| buffer.get(offset..end))
+            .ok_or_else(|| format!("bufferView {} exceeds its buffer", index))?;
+        Ok((bytes, get_usize(view, "byteStride")))
+    }
+
+    // Reads an accessor as floats, applying normalization for integer types
+    fn read_floats(&self, index: usize, components: usize) -> Result<Vec<f32>, String> {
+        let accessor = self.accessor(index)?;
+        let found = type_components(accessor)?;
+        if found != components {
+            return Err(format!("accessor {} has {} components, expected {}", index, found, components));
+        }
+        let normalized = accessor.get("normalized").and_then(|n| n.as_bool()).unwrap_or(false);
+        self.read_accessor(index, &|bytes, component_type| read_component(bytes, component_type, normalized))
+    }
+
+    fn read_indices(&self, index: usize) -> Result<Vec<u32>, String> {
+        let accessor = self.accessor(index)?;
+        match get_usize(accessor, "componentType") {
+            Some(5121) | Some(5123) | Some(5125) => {},
+            _ => return Err(format!("accessor {} is not a valid index type", index)),
+        }
+        // Straight to u32, f32 can't hold indices past 2^24
+        self.read_accessor(index, &read_index)
+    }
+
+    // Decodes every component of an accessor, including sparse substitution
+    fn read_accessor<T: Copy + Default>(&self, index: usize, read: &dyn Fn(&[u8], usize) -> T) -> Result<Vec<T>, String> {
+        let accessor = self.accessor(index)?;
+        let count = get_usize(accessor, "count").ok_or_else(|| format!("accessor {} has no count", index))?;
+        let component_type = get_usize(accessor, "componentType")
+            .ok_or_else(|| format!("accessor {} has no componentType", index))?;
+        let size = component_size(component_type)?;
+        let components = type_components(accessor)?;
+        let too_large = || format!("accessor {} is too large", index);
+        let len = count.checked_mul(components).ok_or_else(too_large)?;
+
+        // Check the data is there before allocating for it
+        let view = match get_usize(accessor, "bufferView") {
+            Some(view) => {
+                let (bytes, stride) = self.buffer_view(view)?;
+                let offset = get_usize(accessor, "byteOffset").unwrap_or(0);
+                let stride = stride.unwrap_or(size * components);
+                if count > 0 && span_end(offset, stride, count, size * components).is_none_or(|end| end > bytes.len()) {
+                    return Err(format!("accessor {} exceeds its bufferView", index));
+                }
+                Some((bytes, offset, stride))
+            },
+            None => None,
+        };
+        let mut values = Vec::new();
+        values.try_reserve_exact(len).map_err(|_| too_large())?;
+        values.resize(len, T::default());
+
+        if let Some((bytes, offset, stride)) = view {
+            for i in 0..count {
+                for c in 0..components {
+                    let start = offset + stride * i + size * c;
+                    values[i * components + c] = read(&bytes[start..start + size], component_type);
+                }
+            }
+        }
+
+        if let Some(sparse) = accessor.get("sparse") {
+            let sparse_count = get_usize(sparse, "count").unwrap_or(0);
+            let sparse_indices = sparse.get("indices").ok_or_else(|| String::from("sparse accessor has no indices"))?;
+            let sparse_values = sparse.get("values").ok_or_else(|| String::from("sparse accessor has no values"))?;
+
+            let index_type = get_usize(sparse_indices, "componentType").unwrap_or(0);
+            if index_type != 5121 && index_type != 5123 && index_type != 5125 {
+                return Err(format!("sparse accessor {} has an invalid index type", index));
+            }
+            let index_size = component_size(index_type)?;
+            let (index_bytes, _) = self.buffer_view(get_usize(sparse_indices, "bufferView").unwrap_or(usize::MAX))?;
+            let index_offset = get_usize(sparse_indices, "byteOffset").unwrap_or(0);
+            let (value_bytes, _) = self.buffer_view(get_usize(sparse_values, "bufferView").unwrap_or(usize::MAX))?;
+            let value_offset = get_usize(sparse_values, "byteOffset").unwrap_or(0);
+
+            if span_end(index_offset, index_size, sparse_count, index_size).is_none_or(|end| end > index_bytes.len())
+                || span_end(value_offset, size * components, sparse_count, size * components).is_none_or(|end| end > value_bytes.len()) {
+                return Err(format!("sparse accessor {} exceeds its bufferViews", index));
+            }
+            for s in 0..sparse_count {
+                let start = index_offset + index_size * s;
+                let target = read_index(&index_bytes[start..start + index_size], index_type) as usize;
+                if target >= count {
+                    return Err(format!("sparse index {} out of range", target));
+                }
+                for c in 0..components {
+                    let start = value_offset + size * (s * components + c);
+                    values[target * components + c] = read(&value_bytes[start..start + size], component_type);
+                }
+            }
+        }
+        Ok(values)
+    }
+}
+
+fn parse_material(material: &Value, index: usize) -> PbrMaterial {
+    let mut m = PbrMaterial { name: name(material, "material", index), ..PbrMaterial::default() };
+    let texture = |v: Option<&Value>| v.and_then(|t| get_usize(t, "index"));
+
+    if let Some(pbr) = material.get("pbrMetallicRoughness") {
+        if let Some(f) = floats(pbr.get("baseColorFactor")) {
+            if f.len() == 4 { m.base_color_factor = Vector4::new(f[0], f[1], f[2], f[3]); }
+        }
+        m.base_color_texture = texture(pbr.get("baseColorTexture"));
+        m.metallic_factor = get_f32(pbr, "metallicFactor").unwrap_or(1.0);
+        m.roughness_factor = get_f32(pbr, "roughnessFactor").unwrap_or(1.0);
+        m.metallic_roughness_texture = texture(pbr.get("metallicRoughnessTexture"));
+    }
+    if let Some(f) = floats(material.get("emissiveFactor")) {
+        if f.len() == 3 { m.emissive_factor = Vector3::new(f[0], f[1], f[2]); }
+    }
+    m.normal_texture = texture(material.get("normalTexture"));
+    m.occlusion_texture = texture(material.get("occlusionTexture"));
+    m.emissive_texture = texture(material.get("emissiveTexture"));
+    m
+}
+
+fn parse_camera(camera: &Value) -> Result<CameraData, String> {
+    match camera.get("type").and_then(|t| t.as_str()) {
+        Some("perspective") => {
+            let p = camera.get("perspective").ok_or_else(|| String::from("missing perspective"))?;
+            Ok(CameraData::Perspective {
+                yfov: get_f32(p, "yfov").ok_or_else(|| String::from("missing yfov"))?,
+                aspect_ratio: get_f32(p, "aspectRatio"),
+                znear: get_f32(p, "znear").ok_or_else(|| String::from("missing znear"))?,
+                zfar: get_f32(p, "zfar"),
+            })
+        },
+        Some("orthographic") => {
+            let o = camera.get("orthographic").ok_or_else(|| String::from("missing orthographic"))?;
+            let field = |key: &str| get_f32(o, key).ok_or_else(|| format!("missing {}", key));
+            Ok(CameraData::Orthographic {
+                xmag: field("xmag")?,
+                ymag: field("ymag")?,
+                znear: field("znear")?,
+                zfar: field("zfar")?,
+            })
+        },
+        t => Err(format!("unknown camera type {:?}", t)),
+    }
+}
+
+fn node_transform(node: &Value) -> Result<Matrix4<f32>, String> {
+    if let Some(m) = floats(node.get("matrix")) {
+        if m.len() != 16 {
+            return Err(String::from("matrix needs 16 values"));
+        }
+        // Both gltf and cgmath are column major
+        return Ok(Matrix4::new(
+            m[0], m[1], m[2], m[3],
+            m[4], m[5], m[6], m[7],
+            m[8], m[9], m[10], m[11],
+            m[12], m[13], m[14], m[15],
+        ));
+    }
+    let mut transform = Matrix4::identity();
+    if let Some(t) = floats(node.get("translation")) {
+        if t.len() != 3 { return Err(String::from("translation needs 3 values")); }
+        transform = transform * Matrix4::from_translation(Vector3::new(t[0], t[1], t[2]));
+    }
+    if let Some(r) = floats(node.get("rotation")) {
+        if r.len() != 4 { return Err(String::from("rotation needs 4 values")); }
+        // Gltf stores quaternions as x, y, z, w
+        transform = transform * Matrix4::from(Quaternion::new(r[3], r[0], r[1], r[2]));
+    }
+    if let Some(s) = floats(node.get("scale")) {
+        if s.len() != 3 { return Err(String::from("scale needs 3 values")); }
+        transform = transform * Matrix4::from_nonuniform_scale(s[0], s[1], s[2]);
+    }
+    Ok(transform)
+}
+
+// Converts strips and fans into a plain triangle list
+fn triangulate(indices: &[u32], mode: u32) -> Vec<u32> {
+    match mode {
+        MODE_TRIANGLE_STRIP => {
+            let mut out = Vec::new();
+            for i in 2..indices.len() {
+                // Every other triangle is flipped to keep the winding
+                if i % 2 == 0 {
+                    out.extend_from_slice(&[indices[i - 2], indices[i - 1], indices[i]]);
+                } else {
+                    out.extend_from_slice(&[indices[i - 1], indices[i - 2], indices[i]]);
+                }
+            }
+            out
+        },
+        MODE_TRIANGLE_FAN => {
+            let mut out = Vec::new();
+            for i in 2..indices.len() {
+                out.extend_from_slice(&[indices[i - 1], indices[i], indices[0]]);
+            }
+            out
+        },
+        _ => indices[..indices.len() - indices.len() % 3].to_vec(),
+    }
+}
+
+fn flat_shaded(positions: &[f32], texcoord: &dyn Fn(usize) -> [f32; 2], indices: &[u32]) -> (Vec<f32>, Vec<u32>) {
+    let mut vertices = Vec::with_capacity(indices.len() * 8);
+    for triangle in indices.chunks(3) {
+        let p = |i: u32| Vector3::new(positions[i as usize * 3], positions[i as usize * 3 + 1], positions[i as usize * 3 + 2]);
+        let normal = (p(triangle[1]) - p(triangle[0])).cross(p(triangle[2]) - p(triangle[0]));
+        let normal = if normal.magnitude2() > 0.0 { normal.normalize() } else { normal };
+        for &i in triangle {
+            let v = p(i);
+            let uv = texcoord(i as usize);
+            vertices.extend_from_slice(&[v.x, v.y, v.z, normal.x, normal.y, normal.z, uv[0], uv[1]]);
+        }
+    }
+    (vertices, (0..indices.len() as u32).collect())
+}
+
+// The end of count elements of element bytes starting stride apart from
+// offset, None on overflow
+fn span_end(offset: usize, stride: usize, count: usize, element: usize) -> Option<usize> {
+    if count == 0 {
+        return Some(offset);
+    }
+    stride.checked_mul(count - 1)?.checked_add(element)?.checked_add(offset)
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    bytes[offset] as u32
+        | (bytes[offset + 1] as u32) << 8
+        | (bytes[offset + 2] as u32) << 16
+        | (bytes[offset + 3] as u32) << 24
+}
+
+fn read_component(bytes: &[u8], component_type: usize, normalized: bool) -> f32 {
+    let u16_at = |b: &[u8]| b[0] as u16 | (b[1] as u16) << 8;
+    match component_type {
+        5120 => { let v = bytes[0] as i8 as f32; if normalized { (v / 127.0).max(-1.0) } else { v } },
+        5121 => { let v = bytes[0] as f32; if normalized { v / 255.0 } else { v } },
+        5122 => { let v = u16_at(bytes) as i16 as f32; if normalized { (v / 32767.0).max(-1.0) } else { v } },
+        5123 => { let v = u16_at(bytes) as f32; if normalized { v / 65535.0 } else { v } },
+        5125 => read_u32(bytes, 0) as f32,
+        _ => f32::from_bits(read_u32(bytes, 0)),
+    }
+}
+
+// Unsigned byte, short or int, the types indices can have
+fn read_index(bytes: &[u8], component_type: usize) -> u32 {
+    match component_type {
+        5121 => bytes[0] as u32,
+        5123 => bytes[0] as u32 | (bytes[1] as u32) << 8,
+        _ => read_u32(bytes, 0),
+    }
+}
+
+fn component_size(component_type: usize) -> Result<usize, String> {
+    match component_type {
+        5120 | 5121 => Ok(1),
+        5122 | 5123 => Ok(2),
+        5125 | 5126 => Ok(4),
+        t => Err(format!("unknown componentType {}", t)),
+    }
+}
+
+fn type_components(accessor: &Value) -> Result<usize, String> {
+    match accessor.get("type").and_then(|t| t.as_str()) {
+        Some("SCALAR") => Ok(1),
+        Some("VEC2") => Ok(2),
+        Some("VEC3") => Ok(3),
+        Some("VEC4") | Some("MAT2") => Ok(4),
+        Some("MAT3") => Ok(9),
+        Some("MAT4") => Ok(16),
+        t => Err(format!("unknown accessor type {:?}", t)),
+    }
+}
+
+fn decode_base64(input: &str) -> Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in input.bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            b'=' => break,
+            b' ' | b'\n' | b'\r' | b'\t' => continue,
+            _ => return Err(format!("Invalid base64 character '{}'", c as char)),
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    Ok(out)
+}
+
+fn percent_decode(uri: &str) -> String {
+    let bytes = uri.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Ok(v) = u8::from_str_radix(&uri[i + 1..i + 3], 16) {
+                out.push(v);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    match value.get(key).and_then(|v| v.as_array()) {
+        Some(a) => a,
+        None => &[],
+    }
+}
+
+fn get_usize(value: &Value, key: &str) -> Option<usize> {
+    value.get(key).and_then(|v| v.as_u64()).map(|v| v as usize)
+}
+
+fn get_f32(value: &Value, key: &str) -> Option<f32> {
+    value.get(key).and_then(|v| v.as_f64()).map(|v| v as f32)
+}
+
+fn floats(value: Option<&Value>) -> Option<Vec<f32>> {
+    value.and_then(|v| v.as_array()).map(|a| a.iter().filter_map(|v| v.as_f64()).map(|v| v as f32).collect())
+}
+
+fn name(value: &Value, kind: &str, index: usize) -> String {
+    match value.get("name").and_then(|n| n.as_str()) {
+        Some(n) => n.to_string(),
+        None => format!("{}{}", kind, index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRIANGLE: &[u8] = include_bytes!("../tests/gltf/triangle.gltf");
+    const QUAD: &[u8] = include_bytes!("../tests/gltf/quad.glb");
+
+    // Wraps json and a binary chunk into a glb
+    fn glb(json: &str, bin: &[u8]) -> Vec<u8> {
+        let mut json = json.as_bytes().to_vec();
+        while !json.len().is_multiple_of(4) {
+            json.push(b' ');
+        }
+        let mut bin = bin.to_vec();
+        while !bin.len().is_multiple_of(4) {
+            bin.push(0);
+        }
+        let mut out = u32_bytes(&[GLB_MAGIC, 2, (12 + 8 + json.len() + 8 + bin.len()) as u32, json.len() as u32, GLB_CHUNK_JSON]);
+        out.extend_from_slice(&json);
+        out.extend(u32_bytes(&[bin.len() as u32, GLB_CHUNK_BIN]));
+        out.extend_from_slice(&bin);
+        out
+    }
+
+    fn u32_bytes(values: &[u32]) -> Vec<u8> {
+        values.iter().flat_map(|v| vec![*v as u8, (v >> 8) as u8, (v >> 16) as u8, (v >> 24) as u8]).collect()
+    }
+
+    fn parse_glb(json: &str, bin: &[u8]) -> Result<GltfData, String> {
+        parse(&glb(json, bin), Path::new(""))
+    }
+
+    #[test]
+    fn decodes_data_uri_gltf() {
+        let data = parse(TRIANGLE, Path::new("")).unwrap();
+        assert_eq!(data.meshes.len(), 1);
+        let primitive = &data.meshes[0].primitives[0];
+        // Interleaved with a 32 byte stride, the second position comes from the sparse values
+        assert_eq!(primitive.vertices, vec![
+            0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0,
+            5.0, 6.0, 7.0, 0.0, 0.0, 1.0, 1.0, 0.0,
+            0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0,
+        ]);
+        assert_eq!(primitive.indices, vec![0, 1, 2]);
+        assert_eq!(primitive.material, Some(0));
+
+        assert_eq!(data.materials[0].name, "red");
+        assert_eq!(data.materials[0].base_color_factor, Vector4::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(data.materials[0].roughness_factor, 0.5);
+        assert_eq!(data.nodes[0].transform, Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)));
+        assert_eq!(data.scenes, vec![vec![0]]);
+        assert_eq!(data.default_scene, Some(0));
+    }
+
+    #[test]
+    fn decodes_glb() {
+        let data = parse(QUAD, Path::new("")).unwrap();
+        let primitive = &data.meshes[0].primitives[0];
+        // Normalized u8 uvs read out of a view padded to 4 bytes per element
+        assert_eq!(primitive.vertices, vec![
+            0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0,
+            1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0,
+            0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0,
+            1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0,
+        ]);
+        // The strip comes back as a list with the second triangle's winding kept
+        assert_eq!(primitive.indices, vec![0, 1, 2, 2, 1, 3]);
+        assert_eq!(primitive.material, None);
+        assert_eq!(data.nodes[0].transform, Matrix4::from_scale(2.0));
+        assert_eq!(root_nodes(&data.nodes), vec![0]);
+    }
+
+    #[test]
+    fn reads_large_indices_exactly() {
+        // 2^24 + 1 rounds to 2^24 as an f32
+        let json = r#"{
+            "buffers": [{"byteLength": 12}],
+            "bufferViews": [{"buffer": 0, "byteLength": 4}, {"buffer": 0, "byteOffset": 4, "byteLength": 4}, {"buffer": 0, "byteOffset": 8, "byteLength": 4}],
+            "accessors": [
+                {"bufferView": 0, "componentType": 5125, "count": 1, "type": "SCALAR"},
+                {"componentType": 5125, "count": 16777218, "type": "SCALAR",
+                 "sparse": {"count": 1, "indices": {"bufferView": 1, "componentType": 5125}, "values": {"bufferView": 2}}}
+            ]
+        }"#;
+        let root: Value = ::serde_json::from_str(json).unwrap();
+        let buffers = vec![u32_bytes(&[16777217, 16777217, 16777219])];
+        let reader = AccessorReader { root: &root, buffers: &buffers };
+        assert_eq!(reader.read_indices(0).unwrap(), vec![16777217]);
+
+        let sparse = reader.read_indices(1).unwrap();
+        assert_eq!(sparse[16777217], 16777219);
+        assert_eq!(sparse[16777216], 0);
+    }
+
+    #[test]
+    fn rejects_out_of_range_indices() {
+        let mut bin = u32_bytes(&[0, 0, 0]);
+        bin.extend(u32_bytes(&[0, 16777217, 0]));
+        let json = r#"{
+            "buffers": [{"byteLength": 24}],
+            "bufferViews": [{"buffer": 0, "byteLength": 12}, {"buffer": 0, "byteOffset": 12, "byteLength": 12}],
+            "accessors": [
+                {"bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3"},
+                {"bufferView": 1, "componentType": 5125, "count": 3, "type": "SCALAR"}
+            ],
+            "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "indices": 1}]}]
+        }"#;
+        assert_eq!(parse_glb(json, &bin).unwrap_err(), "mesh 0: index 16777217 out of range");
+    }
+
+    #[test]
+    fn rejects_bad_accessors() {
+        let bin = u32_bytes(&[0; 6]);
+        let with_accessors = |accessors: &str, primitive: &str| format!(r#"{{
+            "buffers": [{{"byteLength": 24}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": 24}}, {{"buffer": 0, "byteLength": 24, "byteStride": 16}}],
+            "accessors": [{}],
+            "meshes": [{{"primitives": [{}]}}]
+        }}"#, accessors, primitive);
+
+        // Two vec3s need 24 bytes, but the stride puts the second one at 16
+        let strided = with_accessors(r#"{"bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3"}"#,
+                                     r#"{"attributes": {"POSITION": 0}}"#);
+        assert_eq!(parse_glb(&strided, &bin).unwrap_err(), "mesh 0: accessor 0 exceeds its bufferView");
+
+        let float_indices = with_accessors(
+            r#"{"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3"}, {"bufferView": 0, "componentType": 5126, "count": 3, "type": "SCALAR"}"#,
+            r#"{"attributes": {"POSITION": 0}, "indices": 1}"#);
+        assert_eq!(parse_glb(&float_indices, &bin).unwrap_err(), "mesh 0: accessor 1 is not a valid index type");
+
+        let vec2_positions = with_accessors(r#"{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC2"}"#,
+                                            r#"{"attributes": {"POSITION": 0}}"#);
+        assert_eq!(parse_glb(&vec2_positions, &bin).unwrap_err(), "mesh 0: accessor 0 has 2 components, expected 3");
+
+        let unknown_type = with_accessors(r#"{"bufferView": 0, "componentType": 1234, "count": 1, "type": "VEC3"}"#,
+                                          r#"{"attributes": {"POSITION": 0}}"#);
+        assert_eq!(parse_glb(&unknown_type, &bin).unwrap_err(), "mesh 0: unknown componentType 1234");
+
+        let no_position = with_accessors("", r#"{"attributes": {}}"#);
+        assert_eq!(parse_glb(&no_position, &bin).unwrap_err(), "mesh 0: primitive has no POSITION");
+
+        let sparse_out_of_range = with_accessors(
+            r#"{"componentType": 5126, "count": 2, "type": "VEC3",
+                "sparse": {"count": 1, "indices": {"bufferView": 0, "componentType": 5125, "byteOffset": 20}, "values": {"bufferView": 0}}}"#,
+            r#"{"attributes": {"POSITION": 0}}"#);
+        let mut far = bin.clone();
+        far[20] = 2;
+        assert_eq!(parse_glb(&sparse_out_of_range, &far).unwrap_err(), "mesh 0: sparse index 2 out of range");
+
+        // Counts and offsets that overflow are caught before anything is allocated
+        let huge_count = with_accessors(r#"{"bufferView": 0, "componentType": 5126, "count": 1152921504606846976, "type": "VEC3"}"#,
+                                        r#"{"attributes": {"POSITION": 0}}"#);
+        assert_eq!(parse_glb(&huge_count, &bin).unwrap_err(), "mesh 0: accessor 0 exceeds its bufferView");
+        let huge_zeros = huge_count.replace(r#""bufferView": 0, "#, "");
+        assert_eq!(parse_glb(&huge_zeros, &bin).unwrap_err(), "mesh 0: accessor 0 is too large");
+        let overflowing_count = huge_zeros.replace("1152921504606846976", "9223372036854775807");
+        assert_eq!(parse_glb(&overflowing_count, &bin).unwrap_err(), "mesh 0: accessor 0 is too large");
+        let huge_offset = with_accessors(r#"{"bufferView": 0, "byteOffset": 18446744073709551615, "componentType": 5126, "count": 1, "type": "VEC3"}"#,
+                                         r#"{"attributes": {"POSITION": 0}}"#);
+        assert_eq!(parse_glb(&huge_offset, &bin).unwrap_err(), "mesh 0: accessor 0 exceeds its bufferView");
+        let huge_sparse = sparse_out_of_range.replace(r#""count": 1, "indices""#, r#""count": 9223372036854775807, "indices""#);
+        assert_eq!(parse_glb(&huge_sparse, &bin).unwrap_err(), "mesh 0: sparse accessor 0 exceeds its bufferViews");
+        let huge_view = strided.replace(r#"{"buffer": 0, "byteLength": 24}, "#, r#"{"buffer": 0, "byteOffset": 18446744073709551615, "byteLength": 24}, "#)
+            .replace(r#""bufferView": 1"#, r#""bufferView": 0"#);
+        assert_eq!(parse_glb(&huge_view, &bin).unwrap_err(), "mesh 0: bufferView 0 exceeds its buffer");
+    }
+
+    #[test]
+    fn rejects_nodes_that_arent_trees() {
+        let nodes = |nodes: &str| parse(format!(r#"{{"nodes": {}}}"#, nodes).as_bytes(), Path::new(""));
+        assert_eq!(nodes(r#"[{"children": [1]}, {"children": [0]}]"#).unwrap_err(), "node 0 has a cycle in its parents");
+        assert_eq!(nodes(r#"[{"children": [0]}]"#).unwrap_err(), "node 0 has a cycle in its parents");
+        // Node 0 hangs off a cycle without being part of it
+        assert_eq!(nodes(r#"[{}, {"children": [0, 2]}, {"children": [1]}]"#).unwrap_err(), "node 0 has a cycle in its parents");
+        assert_eq!(nodes(r#"[{"children": [2]}, {"children": [2]}, {}]"#).unwrap_err(), "node 2 has more than one parent");
+        assert_eq!(nodes(r#"[{"children": [1, 1]}, {}]"#).unwrap_err(), "node 1 has more than one parent");
+
+        let tree = nodes(r#"[{"children": [1, 2]}, {"children": [3]}, {}, {}, {}]"#).unwrap();
+        assert_eq!(root_nodes(&tree.nodes), vec![0, 4]);
+    }
+
+    #[test]
+    fn rejects_broken_files() {
+        assert_eq!(parse(b"glTF\x02\0\0\0", Path::new("")).unwrap_err(), "Truncated glb header");
+
+        let mut version = glb("{}", &[]);
+        version[4] = 1;
+        assert_eq!(parse(&version, Path::new("")).unwrap_err(), "Unsupported glb version 1");
+
+        let mut truncated = glb("{}", &[0; 8]);
+        let length = truncated.len() - 4;
+        truncated[8] = length as u8;
+        truncated.truncate(length);
+        assert_eq!(parse(&truncated, Path::new("")).unwrap_err(), "Truncated glb chunk");
+
+        let short_buffer = r#"{"buffers": [{"byteLength": 8, "uri": "data:application/octet-stream;base64,AAAA"}]}"#;
+        assert_eq!(parse(short_buffer.as_bytes(), Path::new("")).unwrap_err(), "buffer 0 is 3 bytes, expected 8");
+
+        let bad_base64 = r#"{"buffers": [{"byteLength": 3, "uri": "data:application/octet-stream;base64,AA*A"}]}"#;
+        assert_eq!(parse(bad_base64.as_bytes(), Path::new("")).unwrap_err(), "buffer 0: Invalid base64 character '*'");
+
+        let missing_mesh = r#"{"nodes": [{"mesh": 3}]}"#;
+        assert_eq!(parse(missing_mesh.as_bytes(), Path::new("")).unwrap_err(), "mesh index 3 out of range");
+
+        assert!(parse(b"not json", Path::new("")).unwrap_err().starts_with("Invalid json"));
+    }
+}
@@ -0,0 +1,261 @@
+// Golden image regression checks. Renders the scene from fixed camera poses
+// in a headless context and compares the frames against the PNG references
+// in tests/golden/. Run with --golden, and with BLESS_GOLDEN=1 to replace the
+// references after an intended change.
+use std;
+use std::fs;
+use std::path::Path;
+
+use cgmath::Vector3;
+use stb_image;
+
+use camera::Camera;
+use render_gl;
+use scene::Scene;
+
+// Frames are rendered at this size, the references have to match it
+pub const WIDTH: u32 = 160;
+pub const HEIGHT: u32 = 120;
+// Set to anything but 0 to write the rendered frames as the new references
+pub const BLESS_VAR: &str = "BLESS_GOLDEN";
+// References are RGB, alpha isn't part of what's shown
+const CHANNELS: i32 = 3;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Pose {
+    pub name: &'static str,
+    pub position: Vector3<f32>,
+    pub yaw: f32,
+    pub pitch: f32,
+    // Scene time in seconds, the light's color depends on it
+    pub time: f32,
+}
+
+pub fn poses() -> Vec<Pose> {
+    vec![
+        Pose { name: "front", position: Vector3::new(0.0, 0.0, 3.0), yaw: 270.0, pitch: 0.0, time: 0.0 },
+        Pose { name: "above", position: Vector3::new(-1.5, 1.5, 2.0), yaw: 305.0, pitch: -35.0, time: 1.0 },
+        Pose { name: "lamp", position: Vector3::new(1.2, 0.4, 4.2), yaw: 240.0, pitch: -8.0, time: 2.5 },
+    ]
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Tolerance {
+    // Channel differences up to this are ignored outright
+    pub channel: u8,
+    // YIQ color distance, 0-1, above which a pixel counts as visibly different
+    pub perceptual: f32,
+    // Fraction of the pixels that may differ visibly before the check fails
+    pub max_differing: f32,
+}
+
+impl Default for Tolerance {
+    // Loose enough for rasterization differences between drivers
+    fn default() -> Self {
+        Tolerance { channel: 3, perceptual: 0.1, max_differing: 0.002 }
+    }
+}
+
+pub struct Comparison {
+    // Pixels over the perceptual threshold
+    pub differing: usize,
+    pub max_channel_difference: u8,
+    // RGB, the expected image faded out with visible differences in red and
+    // smaller ones over the channel tolerance in yellow
+    pub diff: Vec<u8>,
+}
+
+impl Comparison {
+    pub fn passes(&self, tolerance: &Tolerance) -> bool {
+        let pixels = self.diff.len() / CHANNELS as usize;
+        self.differing as f32 <= tolerance.max_differing * pixels as f32
+    }
+}
+
+// Compares two RGB images of the same size
+pub fn compare(expected: &[u8], actual: &[u8], tolerance: &Tolerance) -> Comparison {
+    assert_eq!(expected.len(), actual.len(), "Compared images have different sizes");
+    let mut comparison = Comparison { differing: 0, max_channel_difference: 0, diff: Vec::with_capacity(expected.len()) };
+
+    for (a, b) in expected.chunks(3).zip(actual.chunks(3)) {
+        let difference = a.iter().zip(b.iter()).map(|(&a, &b)| (a as i32 - b as i32).unsigned_abs() as u8).max().unwrap_or(0);
+        comparison.max_channel_difference = comparison.max_channel_difference.max(difference);
+
+        let pixel = if difference <= tolerance.channel {
+            let luma = (0.299 * a[0] as f32 + 0.587 * a[1] as f32 + 0.114 * a[2] as f32) as u8;
+            let faded = 255 - (255 - luma) / 4;
+            [faded, faded, faded]
+        } else if perceptual_distance(a, b) > tolerance.perceptual {
+            comparison.differing += 1;
+            [255, 0, 0]
+        } else {
+            [255, 200, 0]
+        };
+        comparison.diff.extend_from_slice(&pixel);
+    }
+    comparison
+}
+
+// Distance between two colors in YIQ space, weighted like pixelmatch does
+// so it follows what the eye notices. 0 for equal colors, 1 for the most
+// different pair, black against white is about 0.97.
+fn perceptual_distance(a: &[u8], b: &[u8]) -> f32 {
+    let yiq = |c: &[u8]| {
+        let (r, g, b) = (c[0] as f32, c[1] as f32, c[2] as f32);
+        (
+            0.298_895_3 * r + 0.586_622_5 * g + 0.114_482_2 * b,
+            0.595_977_9 * r - 0.274_176_4 * g - 0.321_801_5 * b,
+            0.211_470_2 * r - 0.522_617_4 * g + 0.311_147_2 * b,
+        )
+    };
+    let (y1, i1, q1) = yiq(a);
+    let (y2, i2, q2) = yiq(b);
+    let delta = 0.5053 * (y1 - y2).powi(2) + 0.299 * (i1 - i2).powi(2) + 0.1957 * (q1 - q2).powi(2);
+    // 35215 is the delta between black and white
+    (delta / 35215.0).sqrt()
+}
+
+// Renders every pose and checks it, returns whether they all passed. Failed
+// frames are written to the output directory with a diff image next to them.
+pub fn run(scene: &mut Scene, references: &Path, output: &Path, tolerance: &Tolerance) -> bool {
+    let bless = std::env::var(BLESS_VAR).map(|value| value != "0").unwrap_or(false);
+    let mut passed = true;
+
+    for pose in poses() {
+        scene.camera = Camera::new(pose.position, Vector3::new(0.0, 1.0, 0.0), pose.yaw, pose.pitch);
+        scene.render(pose.time, WIDTH, HEIGHT);
+        let actual = render_gl::read_pixels(0, 0, WIDTH, HEIGHT, CHANNELS);
+        let reference = references.join(format!("{}.png", pose.name));
+
+        if bless {
+            match write(&reference, &actual) {
+                Ok(()) => println!("golden {}: blessed {}", pose.name, reference.display()),
+                Err(err) => {
+                    println!("golden {}: {}", pose.name, err);
+                    passed = false;
+                },
+            }
+            continue;
+        }
+
+        let result = load(&reference).and_then(|expected| {
+            let comparison = compare(&expected, &actual, tolerance);
+            if comparison.passes(tolerance) {
+                // Left over from an earlier failure
+                let _ = fs::remove_file(output.join(format!("{}.diff.png", pose.name)));
+                let _ = fs::remove_file(output.join(format!("{}.actual.png", pose.name)));
+                println!("golden {}: ok ({} pixels differ, max channel difference {})",
+                    pose.name, comparison.differing, comparison.max_channel_difference);
+                return Ok(());
+            }
+            let diff = output.join(format!("{}.diff.png", pose.name));
+            write(&diff, &comparison.diff)?;
+            Err(format!("{} pixels differ visibly, max channel difference {}, see {}",
+                comparison.differing, comparison.max_channel_difference, diff.display()))
+        });
+        if let Err(err) = result {
+            passed = false;
+            let actual_path = output.join(format!("{}.actual.png", pose.name));
+            match write(&actual_path, &actual) {
+                Ok(()) => println!("golden {}: FAILED, {} (rendered frame in {})", pose.name, err, actual_path.display()),
+                Err(write_err) => println!("golden {}: FAILED, {} ({})", pose.name, err, write_err),
+            }
+        }
+    }
+    passed
+}
+
+fn load(path: &Path) -> Result<Vec<u8>, String> {
+    if !path.exists() {
+        return Err(format!("{} doesn't exist, run with {}=1 to create it", path.display(), BLESS_VAR));
+    }
+    let options = stb_image::LoadOptions { desired_channels: CHANNELS, flip_vertically: false, premultiply_alpha: false };
+    let image = options.load(&path.to_string_lossy()).map_err(|err| err.to_string())?;
+    if (image.width() as u32, image.height() as u32) != (WIDTH, HEIGHT) {
+        return Err(format!("{} is {}x{}, frames are {}x{}", path.display(), image.width(), image.height(), WIDTH, HEIGHT));
+    }
+    Ok(image.as_slice().to_vec())
+}
+
+fn write(path: &Path, pixels: &[u8]) -> Result<(), String> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(|err| format!("Couldn't create {}: {}", directory.display(), err))?;
+    }
+    stb_image::write_png(&path.to_string_lossy(), WIDTH, HEIGHT, CHANNELS, pixels).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A width x 1 image of one color
+    fn solid(width: usize, color: [u8; 3]) -> Vec<u8> {
+        color.iter().cloned().cycle().take(width * 3).collect()
+    }
+
+    #[test]
+    fn perceptual_distance_ranges() {
+        assert_eq!(perceptual_distance(&[10, 20, 30], &[10, 20, 30]), 0.0);
+        let black_white = perceptual_distance(&[0, 0, 0], &[255, 255, 255]);
+        assert!(black_white > 0.95 && black_white <= 1.0, "{}", black_white);
+        assert_eq!(black_white, perceptual_distance(&[255, 255, 255], &[0, 0, 0]));
+        // Green changes brightness more than blue does
+        let green = perceptual_distance(&[100, 100, 100], &[100, 130, 100]);
+        let blue = perceptual_distance(&[100, 100, 100], &[100, 100, 130]);
+        assert!(green > blue);
+    }
+
+    #[test]
+    fn identical_images_pass() {
+        let image = solid(4, [30, 60, 90]);
+        let comparison = compare(&image, &image, &Tolerance::default());
+        assert_eq!(comparison.differing, 0);
+        assert_eq!(comparison.max_channel_difference, 0);
+        assert!(comparison.passes(&Tolerance::default()));
+        assert_eq!(comparison.diff.len(), image.len());
+    }
+
+    #[test]
+    fn marks_differences_by_size() {
+        let tolerance = Tolerance { channel: 3, perceptual: 0.1, max_differing: 0.0 };
+        // Within the channel tolerance, over it but not visible, and visible
+        let expected = [100, 100, 100, 100, 100, 100, 0, 0, 0];
+        let actual = [103, 97, 100, 108, 100, 100, 255, 255, 255];
+        let comparison = compare(&expected, &actual, &tolerance);
+        assert_eq!(comparison.max_channel_difference, 255);
+        assert_eq!(comparison.differing, 1);
+        assert_eq!(&comparison.diff[3..], &[255, 200, 0, 255, 0, 0]);
+        // Matching pixels are the expected image faded towards white
+        let faded = comparison.diff[0];
+        assert!(faded > 100 && comparison.diff[..3] == [faded, faded, faded]);
+        assert!(!comparison.passes(&tolerance));
+    }
+
+    #[test]
+    fn tolerance_limits_differing_fraction() {
+        let expected = solid(1000, [0, 0, 0]);
+        let mut actual = expected.clone();
+        for pixel in 0..2 {
+            actual[pixel * 3..pixel * 3 + 3].copy_from_slice(&[255, 255, 255]);
+        }
+        let comparison = compare(&expected, &actual, &Tolerance::default());
+        assert_eq!(comparison.differing, 2);
+        // 0.002 of 1000 pixels
+        assert!(comparison.passes(&Tolerance::default()));
+        actual[6..9].copy_from_slice(&[255, 255, 255]);
+        assert!(!compare(&expected, &actual, &Tolerance::default()).passes(&Tolerance::default()));
+
+        // A looser perceptual threshold lets the same pixels through
+        let loose = Tolerance { perceptual: 1.0, max_differing: 0.0, ..Tolerance::default() };
+        assert_eq!(compare(&expected, &actual, &loose).differing, 0);
+        // And a channel tolerance of 255 ignores everything
+        let ignore = Tolerance { channel: 255, ..loose };
+        assert!(compare(&expected, &actual, &ignore).diff.iter().all(|&c| c == 192));
+    }
+
+    #[test]
+    #[should_panic(expected = "different sizes")]
+    fn rejects_different_sizes() {
+        compare(&solid(2, [0, 0, 0]), &solid(3, [0, 0, 0]), &Tolerance::default());
+    }
+}
@@ -1,46 +1,126 @@
 #version 330 core
 out vec4 FragColor;
 
-struct Material {
-    vec3 ambient;
-    vec3 diffuse;
-    vec3 specular;
-    float shininess;
-};
-uniform Material material;
-
-struct Light {
-    vec3 position;
-
-    vec3 ambient;
-    vec3 diffuse;
-    vec3 specular;
-};
-uniform Light light;
+#include "common/lighting.glsl"
 
 uniform vec3 viewPos;
 
 in vec3 Normal;
-in vec3 FragPos; 
+in vec3 FragPos;
+in vec2 TexCoords;
 
-void main()
-{
-    vec3 ambient = light.ambient * material.ambient;
+// Surface colors for this fragment, from the maps or the constants
+vec3 surfaceAmbient;
+vec3 surfaceDiffuse;
+vec3 surfaceSpecular;
 
-    // Direction vectors
-    vec3 norm = normalize(Normal);
-    vec3 lightDir = normalize(light.position - FragPos);
+// Phong terms for light coming from lightDir, without attenuation
+vec3 shade(vec3 lightDir, vec3 ambientColor, vec3 diffuseColor, vec3 specularColor, vec3 normal, vec3 viewDir)
+{
+    vec3 ambient = ambientColor * surfaceAmbient;
 
     // Diffuse
-    float diff = max(dot(norm, lightDir), 0.0);
-    vec3 diffuse = light.diffuse * (diff * material.diffuse);
+    float diff = max(dot(normal, lightDir), 0.0);
+    vec3 diffuse = diffuseColor * (diff * surfaceDiffuse);
 
     // Specular
+    vec3 reflectDir = reflect(-lightDir, normal);
+    float spec = pow(max(dot(viewDir, reflectDir), 0.0), material.shininess);
+    vec3 specular = specularColor * (spec * surfaceSpecular);
+
+    return ambient + diffuse + specular;
+}
+
+#ifdef NORMAL_MAP
+// Builds the tangent frame from screen space derivatives, so meshes don't need tangents
+vec3 perturbNormal(vec3 normal)
+{
+    vec3 mapNormal = texture(material.normalMap, TexCoords).rgb * 2.0 - 1.0;
+
+    vec3 dp1 = dFdx(FragPos);
+    vec3 dp2 = dFdy(FragPos);
+    vec2 duv1 = dFdx(TexCoords);
+    vec2 duv2 = dFdy(TexCoords);
+
+    vec3 dp2perp = cross(dp2, normal);
+    vec3 dp1perp = cross(normal, dp1);
+    vec3 tangent = dp2perp * duv1.x + dp1perp * duv2.x;
+    vec3 bitangent = dp2perp * duv1.y + dp1perp * duv2.y;
+    float invmax = inversesqrt(max(dot(tangent, tangent), dot(bitangent, bitangent)));
+
+    mat3 tbn = mat3(tangent * invmax, bitangent * invmax, normal);
+    return normalize(tbn * mapNormal);
+}
+#endif
+
+float attenuation(float constant, float linear, float quadratic, vec3 position)
+{
+    float distance = length(position - FragPos);
+    return 1.0 / (constant + linear * distance + quadratic * (distance * distance));
+}
+
+vec3 calcDirLight(DirLight light, vec3 normal, vec3 viewDir)
+{
+    vec3 lightDir = normalize(-light.direction);
+    return shade(lightDir, light.ambient, light.diffuse, light.specular, normal, viewDir);
+}
+
+vec3 calcPointLight(PointLight light, vec3 normal, vec3 viewDir)
+{
+    vec3 lightDir = normalize(light.position - FragPos);
+    return shade(lightDir, light.ambient, light.diffuse, light.specular, normal, viewDir)
+        * attenuation(light.constant, light.linear, light.quadratic, light.position);
+}
+
+vec3 calcSpotLight(SpotLight light, vec3 normal, vec3 viewDir)
+{
+    vec3 lightDir = normalize(light.position - FragPos);
+
+    // Soft edge between the inner and outer cone
+    float theta = dot(lightDir, normalize(-light.direction));
+    float epsilon = light.cutOff - light.outerCutOff;
+    float intensity = clamp((theta - light.outerCutOff) / epsilon, 0.0, 1.0);
+
+    vec3 ambient = light.ambient * surfaceAmbient;
+    vec3 lit = shade(lightDir, vec3(0.0), light.diffuse, light.specular, normal, viewDir) * intensity;
+    return (ambient + lit) * attenuation(light.constant, light.linear, light.quadratic, light.position);
+}
+
+void main()
+{
+#ifdef DIFFUSE_MAP
+    // Ambient follows the diffuse map like in learnopengl's lighting maps
+    surfaceDiffuse = texture(material.diffuseMap, TexCoords).rgb;
+    surfaceAmbient = surfaceDiffuse;
+#else
+    surfaceDiffuse = material.diffuse;
+    surfaceAmbient = material.ambient;
+#endif
+#ifdef SPECULAR_MAP
+    surfaceSpecular = texture(material.specularMap, TexCoords).rgb;
+#else
+    surfaceSpecular = material.specular;
+#endif
+#ifdef EMISSION_MAP
+    vec3 emission = texture(material.emissionMap, TexCoords).rgb;
+#else
+    vec3 emission = material.emission;
+#endif
+
+    // Direction vectors
+    vec3 norm = normalize(Normal);
+#ifdef NORMAL_MAP
+    norm = perturbNormal(norm);
+#endif
     vec3 viewDir = normalize(viewPos - FragPos);
-    vec3 reflectDir = reflect(-lightDir, norm);
-    float spec = pow(max(dot(viewDir, reflectDir), 0.0), 32);
-    vec3 specular = light.specular * (spec * material.specular);
 
-    vec3 result = diffuse + specular + ambient;
+    vec3 result = emission;
+    for (int i = 0; i < nrDirLights && i < MAX_DIR_LIGHTS; i++)
+        result += calcDirLight(dirLights[i], norm, viewDir);
+    for (int i = 0; i < nrPointLights && i < MAX_POINT_LIGHTS; i++)
+        result += calcPointLight(pointLights[i], norm, viewDir);
+    for (int i = 0; i < nrSpotLights && i < MAX_SPOT_LIGHTS; i++)
+        result += calcSpotLight(spotLights[i], norm, viewDir);
+
     FragColor = vec4(result, 1.0);
-}
\ No newline at end of file
+}
@@ -0,0 +1,213 @@
+use cgmath::{Vector3, Deg, Angle};
+use std::cell::Cell;
+
+use render_gl;
+
+// Injected into the shaders as defines of the same name
+pub const MAX_DIR_LIGHTS: usize = 4;
+pub const MAX_POINT_LIGHTS: usize = 16;
+pub const MAX_SPOT_LIGHTS: usize = 8;
+
+#[derive(Clone, Copy, Debug)]
+pub struct LightColors {
+    pub ambient: Vector3<f32>,
+    pub diffuse: Vector3<f32>,
+    pub specular: Vector3<f32>,
+}
+
+impl LightColors {
+    pub fn new(ambient: Vector3<f32>, diffuse: Vector3<f32>, specular: Vector3<f32>) -> Self {
+        LightColors { ambient, diffuse, specular }
+    }
+}
+
+// 1 / (constant + linear * d + quadratic * d^2)
+#[derive(Clone, Copy, Debug)]
+pub struct Attenuation {
+    pub constant: f32,
+    pub linear: f32,
+    pub quadratic: f32,
+}
+
+impl Attenuation {
+    pub fn none() -> Self {
+        Attenuation { constant: 1.0, linear: 0.0, quadratic: 0.0 }
+    }
+}
+
+impl Default for Attenuation {
+    // Covers a distance of about 50 units
+    fn default() -> Self {
+        Attenuation { constant: 1.0, linear: 0.09, quadratic: 0.032 }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Light {
+    Directional {
+        direction: Vector3<f32>,
+        colors: LightColors,
+    },
+    Point {
+        position: Vector3<f32>,
+        attenuation: Attenuation,
+        colors: LightColors,
+    },
+    Spot {
+        position: Vector3<f32>,
+        direction: Vector3<f32>,
+        // Full intensity inside cutoff, fading out until outer_cutoff
+        cutoff: Deg<f32>,
+        outer_cutoff: Deg<f32>,
+        attenuation: Attenuation,
+        colors: LightColors,
+    },
+}
+
+impl Light {
+    pub fn colors(&self) -> &LightColors {
+        match *self {
+            Light::Directional { ref colors, .. } => colors,
+            Light::Point { ref colors, .. } => colors,
+            Light::Spot { ref colors, .. } => colors,
+        }
+    }
+
+    pub fn colors_mut(&mut self) -> &mut LightColors {
+        match *self {
+            Light::Directional { ref mut colors, .. } => colors,
+            Light::Point { ref mut colors, .. } => colors,
+            Light::Spot { ref mut colors, .. } => colors,
+        }
+    }
+
+    pub fn set_position(&mut self, new_position: Vector3<f32>) {
+        match *self {
+            Light::Point { ref mut position, .. } | Light::Spot { ref mut position, .. } => *position = new_position,
+            Light::Directional { .. } => {}
+        }
+    }
+}
+
+// The uniform names of one slot in a shader light array, built once so
+// uploading doesn't format strings every frame
+struct SlotNames {
+    position: String,
+    direction: String,
+    ambient: String,
+    diffuse: String,
+    specular: String,
+    constant: String,
+    linear: String,
+    quadratic: String,
+    cut_off: String,
+    outer_cut_off: String,
+}
+
+impl SlotNames {
+    fn new(array: &str, index: usize) -> Self {
+        let field = |field: &str| format!("{}[{}].{}", array, index, field);
+        SlotNames {
+            position: field("position"),
+            direction: field("direction"),
+            ambient: field("ambient"),
+            diffuse: field("diffuse"),
+            specular: field("specular"),
+            constant: field("constant"),
+            linear: field("linear"),
+            quadratic: field("quadratic"),
+            cut_off: field("cutOff"),
+            outer_cut_off: field("outerCutOff"),
+        }
+    }
+
+    fn array(array: &str, len: usize) -> Vec<SlotNames> {
+        (0..len).map(|index| SlotNames::new(array, index)).collect()
+    }
+}
+
+pub struct LightSet {
+    lights: Vec<Light>,
+    dir_names: Vec<SlotNames>,
+    point_names: Vec<SlotNames>,
+    spot_names: Vec<SlotNames>,
+    // Set after the first "Too many lights" message
+    warned: Cell<bool>,
+}
+
+impl LightSet {
+    pub fn new() -> Self {
+        LightSet {
+            lights: Vec::new(),
+            dir_names: SlotNames::array("dirLights", MAX_DIR_LIGHTS),
+            point_names: SlotNames::array("pointLights", MAX_POINT_LIGHTS),
+            spot_names: SlotNames::array("spotLights", MAX_SPOT_LIGHTS),
+            warned: Cell::new(false),
+        }
+    }
+
+    // Returns the index of the light
+    pub fn push(&mut self, light: Light) -> usize {
+        self.lights.push(light);
+        self.lights.len() - 1
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Light> { self.lights.get(index) }
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Light> { self.lights.get_mut(index) }
+    pub fn len(&self) -> usize { self.lights.len() }
+    pub fn is_empty(&self) -> bool { self.lights.is_empty() }
+    pub fn clear(&mut self) { self.lights.clear(); }
+
+    // Uploads every light and the per type counts. Lights over the
+    // shader's limits are skipped.
+    pub fn apply(&self, program: &render_gl::Program) {
+        let (mut dir_count, mut point_count, mut spot_count) = (0, 0, 0);
+
+        for light in self.lights.iter() {
+            match *light {
+                Light::Directional { direction, ref colors } => {
+                    let names = match self.dir_names.get(dir_count) { Some(names) => names, None => continue };
+                    program.set_uniform(&names.direction, direction);
+                    set_colors(program, names, colors);
+                    dir_count += 1;
+                },
+                Light::Point { position, ref attenuation, ref colors } => {
+                    let names = match self.point_names.get(point_count) { Some(names) => names, None => continue };
+                    program.set_uniform(&names.position, position);
+                    set_attenuation(program, names, attenuation);
+                    set_colors(program, names, colors);
+                    point_count += 1;
+                },
+                Light::Spot { position, direction, cutoff, outer_cutoff, ref attenuation, ref colors } => {
+                    let names = match self.spot_names.get(spot_count) { Some(names) => names, None => continue };
+                    program.set_uniform(&names.position, position);
+                    program.set_uniform(&names.direction, direction);
+                    program.set_uniform(&names.cut_off, cutoff.cos());
+                    program.set_uniform(&names.outer_cut_off, outer_cutoff.cos());
+                    set_attenuation(program, names, attenuation);
+                    set_colors(program, names, colors);
+                    spot_count += 1;
+                },
+            }
+        }
+        if dir_count + point_count + spot_count < self.lights.len() && !self.warned.replace(true) {
+            println!("Too many lights, only {} of {} uploaded!", dir_count + point_count + spot_count, self.lights.len());
+        }
+
+        program.set_uniform("nrDirLights", dir_count as i32);
+        program.set_uniform("nrPointLights", point_count as i32);
+        program.set_uniform("nrSpotLights", spot_count as i32);
+    }
+}
+
+fn set_colors(program: &render_gl::Program, names: &SlotNames, colors: &LightColors) {
+    program.set_uniform(&names.ambient, colors.ambient);
+    program.set_uniform(&names.diffuse, colors.diffuse);
+    program.set_uniform(&names.specular, colors.specular);
+}
+
+fn set_attenuation(program: &render_gl::Program, names: &SlotNames, attenuation: &Attenuation) {
+    program.set_uniform(&names.constant, attenuation.constant);
+    program.set_uniform(&names.linear, attenuation.linear);
+    program.set_uniform(&names.quadratic, attenuation.quadratic);
+}
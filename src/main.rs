@@ -5,54 +5,212 @@ extern crate gl;
 extern crate sdl2;
 extern crate stb_image;
 extern crate cgmath;
+extern crate serde_json;
 
+#[macro_use]
 mod render_gl;
 mod camera;
 mod model;
-
-use cgmath::prelude::*;
-use cgmath::{Deg, Matrix4, Vector3};
+mod material;
+mod obj;
+mod gltf;
+mod texture;
+mod light;
+mod shader_library;
+mod context;
+mod scene;
+mod golden;
+mod screenshot;
+mod recording;
+
+use std::path::Path;
+
+use context::{Context, HeadlessBackend, HeadlessContext, WindowContext};
+
+struct Options {
+    // None opens a window
+    headless: Option<HeadlessBackend>,
+    // Checks the scene against the golden images instead of just rendering
+    golden: bool,
+    // Headless renders 1 frame by default, a window runs until it's closed
+    // unless this is given
+    frames: Option<u32>,
+    // Records every frame to a .y4m video or a directory of PNGs
+    record: Option<String>,
+    // Timestep of recorded and headless frames
+    fps: u32,
+    width: u32,
+    height: u32,
+}
 
 fn main() {
-    let mut screen_width = 900;
-    let mut screen_height = 700;
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            println!("{}", err);
+            println!("Usage: learning-opengl [--headless egl|osmesa] [--frames N] [--size WIDTHxHEIGHT] [--golden] [--record PATH] [--fps N]");
+            std::process::exit(2);
+        }
+    };
+    match options.headless {
+        Some(backend) if options.golden => run_golden(backend),
+        Some(backend) => run_headless(backend, &options),
+        None => run_window(&options),
+    }
+}
 
-    let start_time = std::time::SystemTime::now();
+fn parse_options<I: Iterator<Item = String>>(mut args: I) -> Result<Options, String> {
+    let mut options = Options { headless: None, golden: false, frames: None, record: None, fps: 60, width: 900, height: 700 };
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--headless" => {
+                let name = value()?;
+                options.headless = Some(HeadlessBackend::from_name(&name)
+                    .ok_or_else(|| format!("Unknown headless backend \"{}\"", name))?);
+            },
+            "--golden" => options.golden = true,
+            "--frames" => options.frames = Some(value()?.parse().map_err(|_| String::from("--frames needs a number"))?),
+            "--record" => options.record = Some(value()?),
+            "--fps" => {
+                options.fps = value()?.parse().map_err(|_| String::from("--fps needs a number"))?;
+                if options.fps == 0 {
+                    return Err(String::from("--fps has to be more than 0"));
+                }
+            },
+            "--size" => {
+                let size = value()?;
+                let mut parts = size.splitn(2, 'x').map(|part| part.parse::<u32>());
+                match (parts.next(), parts.next()) {
+                    (Some(Ok(width)), Some(Ok(height))) if width > 0 && height > 0 => {
+                        options.width = width;
+                        options.height = height;
+                    },
+                    _ => return Err(format!("Expected --size WIDTHxHEIGHT, got {}", size)),
+                }
+            },
+            _ => return Err(format!("Unknown argument {}", arg)),
+        }
+    }
+    // The golden images need a headless context, EGL unless told otherwise
+    if options.golden && options.headless.is_none() {
+        options.headless = Some(HeadlessBackend::Egl);
+    }
+    Ok(options)
+}
 
-    let sdl = sdl2::init().unwrap();
-    let video_subsystem = sdl.video().unwrap();
-    sdl.mouse().set_relative_mouse_mode(true);
+// Exits with the annotated compiler output if a shader is broken
+fn create_scene() -> scene::Scene {
+    unsafe {
+        gl::ClearColor(0.2, 0.3, 0.3, 1.0);
+        gl::Enable(gl::DEPTH_TEST);
+    }
+    match scene::Scene::new() {
+        Ok(scene) => scene,
+        Err(err) => {
+            println!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+// Renders the frames offscreen at a fixed timestep and exits
+fn run_headless(backend: HeadlessBackend, options: &Options) {
+    let context = create_headless(backend, options.width, options.height);
+    let debug_output = if cfg!(feature = "debug-output") { render_gl::debug::DebugOutput::install() } else { None };
+
+    let mut scene = create_scene();
+    let (width, height) = context.size();
+    let mut recorder = create_recorder(options, width, height);
+    let frames = options.frames.unwrap_or(1);
+    for frame in 0..frames {
+        scene.render(frame as f32 / options.fps as f32, width, height);
+        if let Some(ref mut recorder) = recorder {
+            record_frame(recorder);
+        }
+        context.swap_buffers();
+    }
+    println!("Rendered {} frames at {}x{}", frames, width, height);
+    finish_recording(recorder);
+}
 
-    let gl_attr = video_subsystem.gl_attr();
-    gl_attr.set_context_profile(sdl2::video::GLProfile::Core);
-    gl_attr.set_context_version(3, 3);
+// Exits with 1 when a frame doesn't match its reference
+fn run_golden(backend: HeadlessBackend) {
+    let context = create_headless(backend, golden::WIDTH, golden::HEIGHT);
+    let debug_output = if cfg!(feature = "debug-output") { render_gl::debug::DebugOutput::install() } else { None };
+
+    let mut scene = create_scene();
+    let passed = golden::run(
+        &mut scene,
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden")),
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/target/golden")),
+        &golden::Tolerance::default(),
+    );
+    if !passed {
+        std::process::exit(1);
+    }
+}
 
-    let window = video_subsystem
-        .window("Game", screen_width, screen_height)
-        .opengl()
-        .resizable()
-        .build()
-        .unwrap();
+fn create_headless(backend: HeadlessBackend, width: u32, height: u32) -> HeadlessContext {
+    match HeadlessContext::new(backend, width, height) {
+        Ok(context) => context,
+        Err(err) => {
+            println!("Couldn't create a headless context: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
 
-    let gl_context = window.gl_create_context().unwrap();
-    let gl =
-        gl::load_with(|s| video_subsystem.gl_get_proc_address(s) as *const std::os::raw::c_void);
+// Exits if the output can't be created
+fn create_recorder(options: &Options, width: u32, height: u32) -> Option<recording::Recorder> {
+    let path = options.record.as_ref()?;
+    match recording::Recorder::new(Path::new(path), width, height, options.fps) {
+        Ok(recorder) => {
+            println!("Recording {}x{} at {} fps to {}", width, height, options.fps, path);
+            Some(recorder)
+        },
+        Err(err) => {
+            println!("Couldn't start recording: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
 
-    let mut event_pump = sdl.event_pump().unwrap();
+fn record_frame(recorder: &mut recording::Recorder) {
+    if let Err(err) = recorder.capture() {
+        println!("Couldn't record frame {}: {}", recorder.frames(), err);
+        std::process::exit(1);
+    }
+}
+
+fn finish_recording(recorder: Option<recording::Recorder>) {
+    match recorder.map(|recorder| recorder.finish()) {
+        Some(Ok(frames)) => println!("Recorded {} frames", frames),
+        Some(Err(err)) => println!("Couldn't finish recording: {}", err),
+        None => {},
+    }
+}
+
+fn run_window(options: &Options) {
+    let mut screen_width = options.width;
+    let mut screen_height = options.height;
+
+    let start_time = std::time::SystemTime::now();
+
+    let context = WindowContext::new("Game", screen_width, screen_height).unwrap();
+    context.sdl.mouse().set_relative_mouse_mode(true);
+    // Stays installed for as long as it's alive
+    let debug_output = if cfg!(feature = "debug-output") { render_gl::debug::DebugOutput::install() } else { None };
+
+    let mut event_pump = context.sdl.event_pump().unwrap();
 
     unsafe {
         gl::Viewport(0, 0, screen_width as gl::types::GLint, screen_height as gl::types::GLint);
-        gl::ClearColor(0.2, 0.3, 0.3, 1.0);
-        gl::Enable(gl::DEPTH_TEST);
     }
-    let lightning_shader = render_gl::Program::from_shaders(
-        include_str!("triangle.vert"),
-        include_str!("light.frag")
-    ).unwrap();
-    let lamp_shader = render_gl::Program::from_shaders(
-        include_str!("lamp.vert"),
-        include_str!("lamp.frag")
-    ).unwrap();
+    let mut scene = create_scene();
+    // The scene draws into a multisampled framebuffer that's resolved to the
+    // window, or straight to the window if the driver can't make one
+    let mut offscreen = create_offscreen(screen_width, screen_height);
 
     let mut nr_attribs: gl::types::GLint = 0;
     unsafe {
@@ -60,42 +218,19 @@ fn main() {
     }
     println!("Max vertex attribs {}", nr_attribs);
 
-    let cube = model::Model::cube();
-    let light_vao = model::Model::light();
-
-    // Light location
-    let light_pos = Vector3{x: 0.4f32, y: 0.2, z: 3.0};
-
-    // Camera variables
-    let camera_pos = Vector3{x: 0.0f32, y: 0.0, z: 3.0};
-    let world_up = Vector3{x: 0.0f32, y: 1.0, z: 0.0};
-
-    let yaw = 270.0f32; // For some reason have to put in a lot of yaw at beginning
-    let pitch = 0f32;
-
-    let mut camera = camera::Camera::new(
-        camera_pos, world_up, yaw, pitch);
+    // Recording renders at a fixed timestep however long frames take, at
+    // the size the window started with
+    let mut recorder = create_recorder(options, screen_width, screen_height);
 
     // Time variables
     let mut delta_time = 0f32;
     let mut last_frame = 0f32;
 
+    let mut frames_rendered = 0;
     let mut first_mouse = true;
+    // F12 saves what's on screen, shift + F12 a supersampled version
+    let mut screenshot_request: Option<u32> = None;
 
-    
-    lightning_shader.set_used();
-    lightning_shader.set_vec3("viewPos", camera.get_position().x, camera.get_position().y, camera.get_position().z);
-   
-    lightning_shader.set_vec3("material.ambient", 1.0, 0.5, 0.31);
-    lightning_shader.set_vec3("material.diffuse", 1.0, 0.5, 0.31);
-    lightning_shader.set_vec3("material.specular", 0.5, 0.5, 0.5);
-    lightning_shader.set_float("material.shininess", 32.0);
-    
-    lightning_shader.set_vec3("light.position", light_pos.x, light_pos.y, light_pos.z);
-    lightning_shader.set_vec3("light.ambient",  0.2, 0.2, 0.2);
-    lightning_shader.set_vec3("light.diffuse",  0.5, 0.5, 0.5); // darken the light a bit to fit the scene
-    lightning_shader.set_vec3("light.specular", 1.0, 1.0, 1.0); 
-    
     println!("Starting main!");
     'main: loop {
         for event in event_pump.poll_iter() {
@@ -112,27 +247,36 @@ fn main() {
                         screen_width = width as u32;
                         screen_height = height as u32;
                         gl::Viewport(0, 0, width, height);
+                        if let Some(Err(err)) = offscreen.as_mut().map(|offscreen| offscreen.resize(screen_width, screen_height)) {
+                            println!("Couldn't resize the offscreen framebuffer, drawing to the window: {}", err);
+                            offscreen = None;
+                        }
                     },
                     _ => {}
                 }, // Window events end
-                sdl2::event::Event::KeyDown{keycode: Some(keycode), ..} => {
+                sdl2::event::Event::KeyDown{keycode: Some(keycode), keymod, repeat: false, ..} => {
                     if keycode == sdl2::keyboard::Keycode::Escape {
                         break 'main;
                     }
+                    if keycode == sdl2::keyboard::Keycode::F12 {
+                        let shift = keymod.intersects(sdl2::keyboard::LSHIFTMOD | sdl2::keyboard::RSHIFTMOD);
+                        screenshot_request = Some(if shift { SUPERSAMPLE } else { 1 });
+                    }
                 },
                 sdl2::event::Event::MouseMotion{xrel, yrel, ..} => {
                     if first_mouse { first_mouse = false; break; }
                     let yrel = -yrel;
-                    camera.process_mouse_movement(xrel as f32, yrel as f32);
+                    scene.camera.process_mouse_movement(xrel as f32, yrel as f32);
                 },
                 sdl2::event::Event::MouseWheel{y, ..} => {
-                    camera.process_mouse_scroll(y as f32);
+                    scene.camera.process_mouse_scroll(y as f32);
                 },
                 _ => {}
             }
         }
         {
             use camera::CameraMovement;
+            let camera = &mut scene.camera;
             // Check whether a key is down
             if event_pump.keyboard_state().is_scancode_pressed(sdl2::keyboard::Scancode::W) {
                 camera.process_keyboard(CameraMovement::FORWARD, delta_time)
@@ -145,66 +289,83 @@ fn main() {
                 camera.process_keyboard(CameraMovement::RIGHT, delta_time)
             }
         }
-        unsafe {
-            gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
-        }
 
         // Calculate time
         let current_frame = std::time::SystemTime::now().duration_since(start_time).unwrap();
         let current_frame = duration_into_float(current_frame);
-        let time = current_frame;
+        let mut time = current_frame;
         delta_time = current_frame - last_frame;
         last_frame = current_frame;
+        if let Some(ref recorder) = recorder {
+            time = recorder.time();
+            delta_time = recorder.timestep();
+        }
 
-        // Move light
-        //light_pos.x = (time * 0.4).sin() * 5.0;
-        lightning_shader.set_vec3("light.position", light_pos.x, light_pos.y, light_pos.z);
-        let light_color = Vector3::new(
-            (time * 2.0).sin(),
-            (time * 0.7).sin(),
-            (time * 1.3).sin()
-        );
-
-        let diffuse_color = light_color * 0.5;
-        let ambient_color = diffuse_color * 0.2;
-
-        lightning_shader.set_vec3("light.ambient", ambient_color.x, ambient_color.y, ambient_color.z);
-        lightning_shader.set_vec3("light.diffuse", diffuse_color.x, diffuse_color.y, diffuse_color.z);
-
-        // Projection matrix
-        let projection : Matrix4<f32> = cgmath::PerspectiveFov{
-            fovy: Deg(camera.get_zoom()).into(),
-            aspect: screen_width as f32 / screen_height as f32,
-            near: 0.1,
-            far: 100.0
-        }.into();        
-        let model = Matrix4::from_translation((0.0, 0.0, 0.0).into());
-        // Draw cube
-        lightning_shader.set_used();
-        lightning_shader.set_mat4("view", camera.get_view_matrix().as_ptr());
-        lightning_shader.set_mat4("projection", projection.as_ptr());
-        lightning_shader.set_mat4("model", model.as_ptr());
-        
-
-        unsafe {
-            gl::BindVertexArray(cube.get_vao());
-            gl::DrawArrays(gl::TRIANGLES, 0, 36);
+        if let Some(ref offscreen) = offscreen {
+            offscreen.bind();
         }
+        scene.render(time, screen_width, screen_height);
+        if let Some(Err(err)) = offscreen.as_ref().map(|offscreen| offscreen.blit_to_default(screen_width, screen_height)) {
+            println!("Couldn't show the offscreen framebuffer, drawing to the window: {}", err);
+            offscreen = None;
+        }
+        if let Some(ref mut recorder) = recorder {
+            record_frame(recorder);
+        }
+        if let Some(factor) = screenshot_request.take() {
+            take_screenshot(&mut scene, time, screen_width, screen_height, factor);
+        }
+
+        context.swap_buffers();
+        frames_rendered += 1;
+        if let Some(frames) = options.frames {
+            if frames_rendered >= frames {
+                break 'main;
+            }
+        }
+    }
+    finish_recording(recorder);
+
+    let stats = scene.shaders().stats();
+    println!("Built {} shader variants ({} failed), {} cache hits, {} variants cached",
+             stats.built, stats.failed, stats.cached, scene.shaders().variant_count());
+}
 
-        // Draw lamp
-        let mut model = Matrix4::from_translation(light_pos);
-        model = model * Matrix4::from_scale(0.2);
-        lamp_shader.set_used();
-        lamp_shader.set_mat4("view", camera.get_view_matrix().as_ptr());
-        lamp_shader.set_mat4("projection", projection.as_ptr());
-        lamp_shader.set_mat4("model", model.as_ptr());
-
-        unsafe {
-            gl::BindVertexArray(light_vao.get_vao());
-            gl::DrawArrays(gl::TRIANGLES, 0, 36);
+// Samples per pixel of the window's offscreen framebuffer
+const MSAA_SAMPLES: u32 = 4;
+
+fn create_offscreen(width: u32, height: u32) -> Option<render_gl::Framebuffer> {
+    let framebuffer = render_gl::Framebuffer::builder(width, height)
+        .samples(MSAA_SAMPLES)
+        .color(render_gl::ColorFormat::Rgba8)
+        .depth(render_gl::DepthFormat::Depth24Stencil8, render_gl::DepthStorage::Renderbuffer)
+        .build();
+    match framebuffer {
+        Ok(mut framebuffer) => {
+            framebuffer.set_label("window msaa");
+            Some(framebuffer)
+        },
+        Err(err) => {
+            println!("Couldn't create the offscreen framebuffer, drawing to the window: {}", err);
+            None
         }
+    }
+}
 
-        window.gl_swap_window();
+// Samples per pixel along each axis for shift + F12 screenshots
+const SUPERSAMPLE: u32 = 3;
+
+// Saves into screenshots/ in the working directory. A factor over 1 draws the
+// frame again supersampled, otherwise the frame that was just drawn is saved.
+fn take_screenshot(scene: &mut scene::Scene, time: f32, width: u32, height: u32, factor: u32) {
+    let screenshot = if factor > 1 {
+        screenshot::Screenshot::supersampled(width, height, factor, |width, height| scene.render(time, width, height))
+    } else {
+        Ok(screenshot::Screenshot::from_window(width, height))
+    };
+    match screenshot.and_then(|screenshot| screenshot.save_timestamped(Path::new("screenshots"))) {
+        Ok(path) => println!("Saved {}", path.display()),
+        Err(err) => println!("Couldn't save the screenshot: {}", err),
     }
 }
 
@@ -215,49 +376,3 @@ fn duration_into_float(duration: std::time::Duration) -> f32 {
         let time = secs + ms;
         return time;
 }
-
-fn create_texture(name: &str) -> gl::types::GLuint {
-    let mut texture = 0;
-    unsafe {
-        stb_image::stbi_set_flip_vertically_on_load(true as i32); 
-        // gen texture binding
-        gl::GenTextures(1, &mut texture);
-        gl::BindTexture(gl::TEXTURE_2D, texture);
-
-        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, gl::REPEAT as gl::types::GLint);
-        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, gl::REPEAT as gl::types::GLint);
-
-        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as gl::types::GLint);
-        gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as gl::types::GLint);
-
-        // Load the texture into memory
-        let (mut width, mut height, mut nr_channels) = (0, 0, 0);
-        let data = stb_image::stbi_load(
-            std::ffi::CString::new(format!("Resources/{}", name)).unwrap().as_bytes_with_nul().as_ptr() as *const i8,
-            &mut width, &mut height, &mut nr_channels, 0
-        );
-        
-        
-
-        if width == 0 && height == 0 {
-            panic!("{} wasn't loaded properly!", name);
-        }
-
-        // Push texture to gpu
-        gl::TexImage2D(
-            gl::TEXTURE_2D,
-            0,
-            gl::RGB as gl::types::GLint,
-            width, height,
-            0,
-            if name.ends_with("jpg") {gl::RGB} else {gl::RGBA},
-            gl::UNSIGNED_BYTE,
-            data as *const gl::types::GLvoid
-        );
-        //gl::GenerateMipmap(gl::TEXTURE_2D);
-
-        // Free texture from memory
-        stb_image::stbi_image_free(data as *mut std::os::raw::c_void);
-    }
-    return texture;
-}
\ No newline at end of file
@@ -0,0 +1,81 @@
+use std::rc::Rc;
+
+use cgmath::{Vector3, Zero};
+
+use render_gl;
+use shader_library::Features;
+use texture::Texture2D;
+
+// Texture units the maps are bound to
+pub const DIFFUSE_UNIT: u32 = 0;
+pub const SPECULAR_UNIT: u32 = 1;
+pub const NORMAL_UNIT: u32 = 2;
+pub const EMISSION_UNIT: u32 = 3;
+
+// Constant colors are used for every map that is None
+#[derive(Clone, Debug)]
+pub struct Material {
+    pub name: String,
+    pub ambient: Vector3<f32>,
+    pub diffuse: Vector3<f32>,
+    pub specular: Vector3<f32>,
+    pub emission: Vector3<f32>,
+    pub shininess: f32,
+
+    pub diffuse_map: Option<Rc<Texture2D>>,
+    pub specular_map: Option<Rc<Texture2D>>,
+    pub normal_map: Option<Rc<Texture2D>>,
+    pub emission_map: Option<Rc<Texture2D>>,
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Material {
+            name: String::from("default"),
+            ambient: Vector3{x: 1.0, y: 0.5, z: 0.31},
+            diffuse: Vector3{x: 1.0, y: 0.5, z: 0.31},
+            specular: Vector3{x: 0.5, y: 0.5, z: 0.5},
+            emission: Vector3::zero(),
+            shininess: 32.0,
+
+            diffuse_map: None,
+            specular_map: None,
+            normal_map: None,
+            emission_map: None,
+        }
+    }
+}
+
+impl Material {
+    // The shader variant defines for the maps this material has
+    pub fn features(&self) -> Features {
+        Features::new()
+            .flag_if("DIFFUSE_MAP", self.diffuse_map.is_some())
+            .flag_if("SPECULAR_MAP", self.specular_map.is_some())
+            .flag_if("NORMAL_MAP", self.normal_map.is_some())
+            .flag_if("EMISSION_MAP", self.emission_map.is_some())
+    }
+
+    // Pushes the material into the "material" struct uniform of the shader
+    // and binds its maps. The program should be the variant for features().
+    pub fn apply(&self, program: &render_gl::Program) {
+        program.set_uniform("material.ambient", self.ambient);
+        program.set_uniform("material.diffuse", self.diffuse);
+        program.set_uniform("material.specular", self.specular);
+        program.set_uniform("material.emission", self.emission);
+        program.set_uniform("material.shininess", self.shininess);
+
+        bind_map(program, "diffuseMap", &self.diffuse_map, DIFFUSE_UNIT);
+        bind_map(program, "specularMap", &self.specular_map, SPECULAR_UNIT);
+        bind_map(program, "normalMap", &self.normal_map, NORMAL_UNIT);
+        bind_map(program, "emissionMap", &self.emission_map, EMISSION_UNIT);
+    }
+}
+
+fn bind_map(program: &render_gl::Program, sampler: &str, map: &Option<Rc<Texture2D>>, unit: u32) {
+    if let Some(ref texture) = *map {
+        // Each sampler gets its own unit so no two sampler types ever share one
+        program.set_uniform(&format!("material.{}", sampler), unit as i32);
+        texture.bind(unit);
+    }
+}
@@ -1,178 +1,339 @@
 use gl;
 use std;
+use std::collections::HashMap;
 
+use material::Material;
+use render_gl::{Buffer, BufferTarget, Usage, VertexArray};
+use render_gl::vertex::{self, AttributeType, Vertex, VertexLayout};
+
+// Attribute locations the shaders agree on
+pub const POSITION: u32 = 0;
+pub const NORMAL: u32 = 1;
+pub const TEXCOORD: u32 = 2;
+pub const TANGENT: u32 = 3;
+pub const COLOR: u32 = 4;
+pub const JOINTS: u32 = 5;
+pub const WEIGHTS: u32 = 6;
+
+// The layout obj and gltf models are loaded into
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct StandardVertex {
+    pub position: [f32; 3],
+    pub normal: [f32; 3],
+    pub uv: [f32; 2],
+}
+
+impl_vertex!(StandardVertex { position: POSITION, normal: NORMAL, uv: TEXCOORD });
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Primitive {
+    Points,
+    Lines,
+    LineStrip,
+    LineLoop,
+    Triangles,
+    TriangleStrip,
+    TriangleFan,
+}
+
+impl Primitive {
+    pub fn to_gl(self) -> gl::types::GLenum {
+        match self {
+            Primitive::Points => gl::POINTS,
+            Primitive::Lines => gl::LINES,
+            Primitive::LineStrip => gl::LINE_STRIP,
+            Primitive::LineLoop => gl::LINE_LOOP,
+            Primitive::Triangles => gl::TRIANGLES,
+            Primitive::TriangleStrip => gl::TRIANGLE_STRIP,
+            Primitive::TriangleFan => gl::TRIANGLE_FAN,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexType {
+    U16,
+    U32,
+}
+
+impl IndexType {
+    // The smallest type that can index vertex_count vertices
+    pub fn for_vertex_count(vertex_count: usize) -> Self {
+        if vertex_count <= u16::MAX as usize + 1 { IndexType::U16 } else { IndexType::U32 }
+    }
+
+    pub fn to_gl(self) -> gl::types::GLenum {
+        match self {
+            IndexType::U16 => gl::UNSIGNED_SHORT,
+            IndexType::U32 => gl::UNSIGNED_INT,
+        }
+    }
+}
 
 pub struct Model {
-    vao : gl::types::GLuint,
+    vertex_array : VertexArray,
+    vertex_buffer : Buffer,
+    // None when drawn without indices
+    index_buffer : Option<Buffer>,
+    vertex_count : usize,
+    index_count : usize,
+    index_type : Option<IndexType>,
+    primitive : Primitive,
+    layout : VertexLayout,
+    material : Material,
 }
 
 impl Model{
+    // Vertices are position, normal and texture coordinates, 8 floats each
     pub fn new(vertices : Vec<f32>, indices : Vec<gl::types::GLuint>) -> Self {
-        let vbo = create_triangle_vbo(vertices);
-        let ebo = create_square_ebo(indices);
+        Self::from_layout(float_bytes(&vertices), indices, StandardVertex::layout())
+    }
+
+    pub fn from_vertices<V: Vertex>(vertices: &[V], indices: Vec<gl::types::GLuint>) -> Self {
+        Self::from_layout(vertex::as_bytes(vertices), indices, V::layout())
+    }
+
+    // Raw vertex data in any layout, the vertex array is set up from it.
+    // Without indices the vertices are drawn in order.
+    pub fn from_layout(data: &[u8], indices: Vec<gl::types::GLuint>, layout: VertexLayout) -> Self {
+        let vertex_count = if layout.stride() == 0 { 0 } else { data.len() / layout.stride() };
+        let index_count = indices.len();
+        let vertex_array = VertexArray::new();
+        let vertex_buffer = Buffer::from_data(BufferTarget::Array, Usage::StaticDraw, data);
+        vertex_array.set_vertex_buffer(&vertex_buffer, &layout, 0);
+
+        let index_type = index_type_for(&indices, vertex_count);
+        let index_buffer = index_type.map(|index_type| {
+            let buffer = match index_type {
+                IndexType::U16 => {
+                    let indices: Vec<u16> = indices.iter().map(|&index| index as u16).collect();
+                    Buffer::from_data(BufferTarget::ElementArray, Usage::StaticDraw, &indices)
+                },
+                IndexType::U32 => Buffer::from_data(BufferTarget::ElementArray, Usage::StaticDraw, &indices),
+            };
+            vertex_array.set_index_buffer(&buffer);
+            buffer
+        });
+
         Model {
-            vao: create_triangle_vao(vbo, ebo)
+            vertex_array,
+            vertex_buffer,
+            index_buffer,
+            vertex_count,
+            index_count,
+            index_type,
+            primitive: Primitive::Triangles,
+            layout,
+            material: Material::default(),
         }
     }
+
+    // Vertices listed once per use, like a triangle soup. Repeated vertices
+    // are merged and drawn through indices instead.
+    pub fn deduplicated(data: &[u8], layout: VertexLayout) -> Self {
+        let (unique, indices) = deduplicate(data, layout.stride());
+        Self::from_layout(&unique, indices, layout)
+    }
+
     pub fn cube() -> Self {
-        let indices: Vec<gl::types::GLuint> = vec![0, 1, 3, 1, 2, 3];
-        Self::new(get_cube_vertices(), indices)
+        Self::deduplicated(float_bytes(&get_cube_vertices()), StandardVertex::layout())
     }
 
-    
+    // The cube with only positions, the lamp shader doesn't light it
     pub fn light() -> Self {
-        let indices: Vec<gl::types::GLuint> = vec![0, 1, 3, 1, 2, 3];
-        let vbo = create_triangle_vbo(get_cube_vertices());
-        let ebo = create_square_ebo(indices);
-        Model {
-            vao: create_triangle_vao(vbo, ebo)
-        }
+        let layout = VertexLayout::new()
+            .push(POSITION, 3, AttributeType::Float, false)
+            .with_stride(std::mem::size_of::<StandardVertex>());
+        Self::deduplicated(float_bytes(&get_cube_vertices()), layout)
     }
 
-    pub fn get_vao(&self) -> gl::types::GLuint { self.vao }
-}
+    // Triangles unless told otherwise
+    pub fn with_primitive(mut self, primitive: Primitive) -> Self {
+        self.primitive = primitive;
+        self
+    }
 
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.material = material;
+        self
+    }
 
-fn create_triangle_vbo(vertices: Vec<f32>) -> gl::types::GLuint {
-    use std::mem::size_of;
+    // Labels the vertex array and buffers "name vao", "name vbo"... and the
+    // material's maps, for debug output and captures. Call it after with_material.
+    pub fn with_label(self, name: &str) -> Self {
+        self.vertex_array.set_label(&format!("{} vao", name));
+        self.vertex_buffer.set_label(&format!("{} vbo", name));
+        if let Some(ref index_buffer) = self.index_buffer {
+            index_buffer.set_label(&format!("{} ebo", name));
+        }
+        let maps = [
+            ("diffuse", &self.material.diffuse_map),
+            ("specular", &self.material.specular_map),
+            ("normal", &self.material.normal_map),
+            ("emission", &self.material.emission_map),
+        ];
+        for (map, texture) in maps.iter() {
+            if let Some(texture) = texture {
+                texture.set_label(&format!("{} {} map", name, map));
+            }
+        }
+        self
+    }
 
-    let mut vbo: gl::types::GLuint = 0;
-    unsafe {
-        gl::GenBuffers(1, &mut vbo);
-        gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
-        gl::BufferData(
-            gl::ARRAY_BUFFER,
-            (vertices.len() * size_of::<f32>()) as gl::types::GLsizeiptr, // size of data in bytes
-            vertices.as_ptr() as *const gl::types::GLvoid,                // pointer to data
-            gl::STATIC_DRAW,                                              // usage
-        );
+    // Draws every vertex, through the indices if there are any, with the
+    // bound program
+    pub fn draw(&self) {
+        self.vertex_array.bind();
+        unsafe {
+            match self.index_type {
+                Some(index_type) => gl_check!(gl::DrawElements(
+                    self.primitive.to_gl(),
+                    self.index_count as gl::types::GLsizei,
+                    index_type.to_gl(),
+                    std::ptr::null(),
+                )),
+                None => gl_check!(gl::DrawArrays(self.primitive.to_gl(), 0, self.vertex_count as gl::types::GLsizei)),
+            }
+        }
+        // So buffers bound after drawing can't end up in this model's vertex array
+        VertexArray::unbind();
     }
 
-    return vbo;
+    pub fn get_vao(&self) -> gl::types::GLuint { self.vertex_array.id() }
+    pub fn get_vertex_count(&self) -> usize { self.vertex_count }
+    pub fn get_index_count(&self) -> usize { self.index_count }
+    pub fn get_index_type(&self) -> Option<IndexType> { self.index_type }
+    pub fn get_primitive(&self) -> Primitive { self.primitive }
+    pub fn get_layout(&self) -> &VertexLayout { &self.layout }
+    pub fn get_material(&self) -> &Material { &self.material }
 }
 
-fn create_square_ebo(indices: Vec<gl::types::GLuint>) -> gl::types::GLuint {
-    use std::mem::size_of;
-
-    let mut ebo: gl::types::GLuint = 0;
-    unsafe {
-        gl::GenBuffers(1, &mut ebo);
-        gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, ebo);
-
-        // Copy index array to gl
-        gl::BufferData(
-            gl::ELEMENT_ARRAY_BUFFER,
-            (indices.len() * size_of::<gl::types::GLuint>()) as gl::types::GLsizeiptr,
-            indices.as_ptr() as *const gl::types::GLvoid,
-            gl::STATIC_DRAW
-        );
-    }
 
-    return ebo;
+// None without indices. An index past the vertices would read outside the
+// vertex buffer, and could wrap when narrowed to u16, so it panics.
+fn index_type_for(indices: &[gl::types::GLuint], vertex_count: usize) -> Option<IndexType> {
+    let max = *indices.iter().max()?;
+    assert!((max as usize) < vertex_count, "Index {} out of range for {} vertices", max, vertex_count);
+    Some(IndexType::for_vertex_count(vertex_count))
 }
 
-fn create_triangle_vao(vbo: gl::types::GLuint, ebo: gl::types::GLuint) -> gl::types::GLuint {
-    let mut vao: gl::types::GLuint = 0;
-    unsafe {
-        gl::GenVertexArrays(1, &mut vao);
-        gl::BindVertexArray(vao);
-
-        // Copy vertice array to gl
-        gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
-        
-        gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, ebo);
-
-        // Set Position vertex attrib pointers
-        gl::VertexAttribPointer(
-            0,
-            3,
-            gl::FLOAT,
-            gl::FALSE,
-            (6 * std::mem::size_of::<f32>()) as gl::types::GLint, // stride (byte offset between consecutive attributes)
-            std::ptr::null(),
-        );
-        gl::EnableVertexAttribArray(0);
-        // Tex
-        gl::VertexAttribPointer(
-            1,
-            3,
-            gl::FLOAT,
-            gl::FALSE,
-            (6 * std::mem::size_of::<f32>()) as gl::types::GLint, // stride (byte offset between consecutive attributes)
-            (3 * std::mem::size_of::<f32>()) as *const gl::types::GLvoid,
-        );
-        gl::EnableVertexAttribArray(1);
-    }
-    return vao;
+// Merges byte-identical vertices, returns the unique ones in order of first
+// use and an index per input vertex
+fn deduplicate(data: &[u8], stride: usize) -> (Vec<u8>, Vec<gl::types::GLuint>) {
+    if stride == 0 {
+        return (Vec::new(), Vec::new());
+    }
+    let mut unique = Vec::with_capacity(data.len());
+    let mut indices = Vec::with_capacity(data.len() / stride);
+    let mut seen: HashMap<&[u8], gl::types::GLuint> = HashMap::new();
+    for vertex in data.chunks_exact(stride) {
+        let next = seen.len() as gl::types::GLuint;
+        let index = *seen.entry(vertex).or_insert(next);
+        if index == next {
+            unique.extend_from_slice(vertex);
+        }
+        indices.push(index);
+    }
+    (unique, indices)
 }
 
-fn create_light_vao(vbo: gl::types::GLuint, ebo: gl::types::GLuint) -> gl::types::GLuint {
-    let mut vao: gl::types::GLuint = 0;
-    unsafe {
-        gl::GenVertexArrays(1, &mut vao);
-        gl::BindVertexArray(vao);
-
-        // Copy vertice array to gl
-        gl::BindBuffer(gl::ARRAY_BUFFER, vbo);
-        
-        gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, ebo);
-
-        // Set Position vertex attrib pointers
-        gl::VertexAttribPointer(
-            0,
-            3,
-            gl::FLOAT,
-            gl::FALSE,
-            (6 * std::mem::size_of::<f32>()) as gl::types::GLint, // stride (byte offset between consecutive attributes)
-            std::ptr::null(),
-        );
-        gl::EnableVertexAttribArray(0);
-    }
-    return vao;
+fn float_bytes(floats: &[f32]) -> &[u8] {
+    unsafe { std::slice::from_raw_parts(floats.as_ptr() as *const u8, std::mem::size_of_val(floats)) }
 }
 
 fn get_cube_vertices() -> Vec<f32> {
         vec![
-    -0.5, -0.5, -0.5,  0.0,  0.0, -1.0,
-     0.5, -0.5, -0.5,  0.0,  0.0, -1.0, 
-     0.5,  0.5, -0.5,  0.0,  0.0, -1.0, 
-     0.5,  0.5, -0.5,  0.0,  0.0, -1.0, 
-    -0.5,  0.5, -0.5,  0.0,  0.0, -1.0, 
-    -0.5, -0.5, -0.5,  0.0,  0.0, -1.0, 
-
-    -0.5, -0.5,  0.5,  0.0,  0.0, 1.0,
-     0.5, -0.5,  0.5,  0.0,  0.0, 1.0,
-     0.5,  0.5,  0.5,  0.0,  0.0, 1.0,
-     0.5,  0.5,  0.5,  0.0,  0.0, 1.0,
-    -0.5,  0.5,  0.5,  0.0,  0.0, 1.0,
-    -0.5, -0.5,  0.5,  0.0,  0.0, 1.0,
-
-    -0.5,  0.5,  0.5, -1.0,  0.0,  0.0,
-    -0.5,  0.5, -0.5, -1.0,  0.0,  0.0,
-    -0.5, -0.5, -0.5, -1.0,  0.0,  0.0,
-    -0.5, -0.5, -0.5, -1.0,  0.0,  0.0,
-    -0.5, -0.5,  0.5, -1.0,  0.0,  0.0,
-    -0.5,  0.5,  0.5, -1.0,  0.0,  0.0,
-
-     0.5,  0.5,  0.5,  1.0,  0.0,  0.0,
-     0.5,  0.5, -0.5,  1.0,  0.0,  0.0,
-     0.5, -0.5, -0.5,  1.0,  0.0,  0.0,
-     0.5, -0.5, -0.5,  1.0,  0.0,  0.0,
-     0.5, -0.5,  0.5,  1.0,  0.0,  0.0,
-     0.5,  0.5,  0.5,  1.0,  0.0,  0.0,
-
-    -0.5, -0.5, -0.5,  0.0, -1.0,  0.0,
-     0.5, -0.5, -0.5,  0.0, -1.0,  0.0,
-     0.5, -0.5,  0.5,  0.0, -1.0,  0.0,
-     0.5, -0.5,  0.5,  0.0, -1.0,  0.0,
-    -0.5, -0.5,  0.5,  0.0, -1.0,  0.0,
-    -0.5, -0.5, -0.5,  0.0, -1.0,  0.0,
-
-    -0.5,  0.5, -0.5,  0.0,  1.0,  0.0,
-     0.5,  0.5, -0.5,  0.0,  1.0,  0.0,
-     0.5,  0.5,  0.5,  0.0,  1.0,  0.0,
-     0.5,  0.5,  0.5,  0.0,  1.0,  0.0,
-    -0.5,  0.5,  0.5,  0.0,  1.0,  0.0,
-    -0.5,  0.5, -0.5,  0.0,  1.0,  0.0
+    -0.5, -0.5, -0.5,  0.0,  0.0, -1.0,  0.0, 0.0,
+     0.5, -0.5, -0.5,  0.0,  0.0, -1.0,  1.0, 0.0,
+     0.5,  0.5, -0.5,  0.0,  0.0, -1.0,  1.0, 1.0,
+     0.5,  0.5, -0.5,  0.0,  0.0, -1.0,  1.0, 1.0,
+    -0.5,  0.5, -0.5,  0.0,  0.0, -1.0,  0.0, 1.0,
+    -0.5, -0.5, -0.5,  0.0,  0.0, -1.0,  0.0, 0.0,
+
+    -0.5, -0.5,  0.5,  0.0,  0.0, 1.0,  0.0, 0.0,
+     0.5, -0.5,  0.5,  0.0,  0.0, 1.0,  1.0, 0.0,
+     0.5,  0.5,  0.5,  0.0,  0.0, 1.0,  1.0, 1.0,
+     0.5,  0.5,  0.5,  0.0,  0.0, 1.0,  1.0, 1.0,
+    -0.5,  0.5,  0.5,  0.0,  0.0, 1.0,  0.0, 1.0,
+    -0.5, -0.5,  0.5,  0.0,  0.0, 1.0,  0.0, 0.0,
+
+    -0.5,  0.5,  0.5, -1.0,  0.0,  0.0,  1.0, 0.0,
+    -0.5,  0.5, -0.5, -1.0,  0.0,  0.0,  1.0, 1.0,
+    -0.5, -0.5, -0.5, -1.0,  0.0,  0.0,  0.0, 1.0,
+    -0.5, -0.5, -0.5, -1.0,  0.0,  0.0,  0.0, 1.0,
+    -0.5, -0.5,  0.5, -1.0,  0.0,  0.0,  0.0, 0.0,
+    -0.5,  0.5,  0.5, -1.0,  0.0,  0.0,  1.0, 0.0,
+
+     0.5,  0.5,  0.5,  1.0,  0.0,  0.0,  1.0, 0.0,
+     0.5,  0.5, -0.5,  1.0,  0.0,  0.0,  1.0, 1.0,
+     0.5, -0.5, -0.5,  1.0,  0.0,  0.0,  0.0, 1.0,
+     0.5, -0.5, -0.5,  1.0,  0.0,  0.0,  0.0, 1.0,
+     0.5, -0.5,  0.5,  1.0,  0.0,  0.0,  0.0, 0.0,
+     0.5,  0.5,  0.5,  1.0,  0.0,  0.0,  1.0, 0.0,
+
+    -0.5, -0.5, -0.5,  0.0, -1.0,  0.0,  0.0, 1.0,
+     0.5, -0.5, -0.5,  0.0, -1.0,  0.0,  1.0, 1.0,
+     0.5, -0.5,  0.5,  0.0, -1.0,  0.0,  1.0, 0.0,
+     0.5, -0.5,  0.5,  0.0, -1.0,  0.0,  1.0, 0.0,
+    -0.5, -0.5,  0.5,  0.0, -1.0,  0.0,  0.0, 0.0,
+    -0.5, -0.5, -0.5,  0.0, -1.0,  0.0,  0.0, 1.0,
+
+    -0.5,  0.5, -0.5,  0.0,  1.0,  0.0,  0.0, 1.0,
+     0.5,  0.5, -0.5,  0.0,  1.0,  0.0,  1.0, 1.0,
+     0.5,  0.5,  0.5,  0.0,  1.0,  0.0,  1.0, 0.0,
+     0.5,  0.5,  0.5,  0.0,  1.0,  0.0,  1.0, 0.0,
+    -0.5,  0.5,  0.5,  0.0,  1.0,  0.0,  0.0, 0.0,
+    -0.5,  0.5, -0.5,  0.0,  1.0,  0.0,  0.0, 1.0
         ]
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_repeated_vertices() {
+        let (unique, indices) = deduplicate(b"aabbaaccbbaa", 2);
+        assert_eq!(unique, b"aabbcc");
+        assert_eq!(indices, vec![0, 1, 0, 2, 1, 0]);
+
+        // A trailing partial vertex is dropped
+        let (unique, indices) = deduplicate(b"abcabcab", 3);
+        assert_eq!(unique, b"abc");
+        assert_eq!(indices, vec![0, 0]);
+
+        assert_eq!(deduplicate(b"abc", 0), (Vec::new(), Vec::new()));
+    }
+
+    #[test]
+    fn merges_the_cube_corners() {
+        let vertices = get_cube_vertices();
+        let (unique, indices) = deduplicate(float_bytes(&vertices), std::mem::size_of::<StandardVertex>());
+        // 4 corners per face, each triangle pair shares a diagonal
+        assert_eq!(unique.len(), 24 * std::mem::size_of::<StandardVertex>());
+        assert_eq!(indices.len(), 36);
+        assert_eq!(&indices[..6], &[0, 1, 2, 2, 3, 0]);
+        let stride = std::mem::size_of::<StandardVertex>();
+        for (i, &index) in indices.iter().enumerate() {
+            let index = index as usize;
+            assert_eq!(unique[index * stride..(index + 1) * stride], float_bytes(&vertices)[i * stride..(i + 1) * stride]);
+        }
+    }
+
+    #[test]
+    fn picks_the_smallest_index_type() {
+        assert_eq!(index_type_for(&[], 10), None);
+        assert_eq!(index_type_for(&[0, 9, 3], 10), Some(IndexType::U16));
+        assert_eq!(index_type_for(&[65535], 65536), Some(IndexType::U16));
+        assert_eq!(index_type_for(&[65536], 65537), Some(IndexType::U32));
+        assert_eq!(index_type_for(&[0], 70000), Some(IndexType::U32));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 65536 out of range for 10 vertices")]
+    fn rejects_indices_past_the_vertices() {
+        // Narrowed to u16 this would silently become 0
+        index_type_for(&[0, 65536], 10);
+    }
+}
@@ -0,0 +1,613 @@
+use std;
+use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
+
+use cgmath::{Vector2, Vector3, InnerSpace, Zero};
+
+use material::Material;
+use model::Model;
+use texture::Texture2D;
+
+// Material parsed from a .mtl library
+#[derive(Clone, Debug)]
+pub struct MtlMaterial {
+    pub name: String,
+    pub ambient: Vector3<f32>,
+    pub diffuse: Vector3<f32>,
+    pub specular: Vector3<f32>,
+    pub emission: Vector3<f32>,
+    pub shininess: f32,
+    pub dissolve: f32,
+    pub illum: i32,
+
+    pub diffuse_map: Option<String>,
+    pub specular_map: Option<String>,
+    pub normal_map: Option<String>,
+    pub emission_map: Option<String>,
+}
+
+impl MtlMaterial {
+    fn new(name: &str) -> Self {
+        let defaults = Material::default();
+        MtlMaterial {
+            name: name.to_string(),
+            ambient: defaults.ambient,
+            diffuse: defaults.diffuse,
+            specular: defaults.specular,
+            emission: Vector3::zero(),
+            shininess: defaults.shininess,
+            dissolve: 1.0,
+            illum: 2,
+
+            diffuse_map: None,
+            specular_map: None,
+            normal_map: None,
+            emission_map: None,
+        }
+    }
+
+    // Loads the maps relative to dir, maps that fail to load fall back to the constant colors
+    pub fn to_material(&self, dir: &Path) -> Material {
+        Material {
+            name: self.name.clone(),
+            ambient: self.ambient,
+            diffuse: self.diffuse,
+            specular: self.specular,
+            emission: self.emission,
+            shininess: self.shininess,
+
+            diffuse_map: load_map(dir, &self.diffuse_map),
+            specular_map: load_map(dir, &self.specular_map),
+            normal_map: load_map(dir, &self.normal_map),
+            emission_map: load_map(dir, &self.emission_map),
+        }
+    }
+}
+
+// One submesh of an obj file, a run of faces sharing object/group and material.
+// Vertices use the position + normal + uv layout of model::StandardVertex.
+#[derive(Clone, Debug)]
+pub struct ObjMesh {
+    pub name: String,
+    pub material: Option<String>,
+    pub vertices: Vec<f32>,
+    pub indices: Vec<u32>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ObjData {
+    pub meshes: Vec<ObjMesh>,
+    pub material_libs: Vec<String>,
+}
+
+// Loads an obj file and its material libraries into one Model per submesh.
+// mtllib paths are resolved relative to the obj file, texture maps relative to the mtl file.
+pub fn load(path: &str) -> Result<Vec<Model>, String> {
+    let source = std::fs::read_to_string(path)
+        .map_err(|e| format!("Couldn't read \"{}\": {}", path, e))?;
+    let data = parse_obj(&source).map_err(|e| format!("{}: {}", path, e))?;
+
+    let dir = Path::new(path).parent().unwrap_or(Path::new(""));
+    let mut materials = HashMap::new();
+    let mut loaded: HashMap<String, Material> = HashMap::new();
+    for lib in data.material_libs.iter() {
+        let lib_path = dir.join(lib);
+        let lib_source = std::fs::read_to_string(&lib_path)
+            .map_err(|e| format!("Couldn't read \"{}\": {}", lib_path.display(), e))?;
+        let parsed = parse_mtl(&lib_source)
+            .map_err(|e| format!("{}: {}", lib_path.display(), e))?;
+        // Map paths are relative to the library
+        let lib_dir = lib_path.parent().unwrap_or(Path::new("")).to_path_buf();
+        for m in parsed {
+            materials.insert(m.name.clone(), (m, lib_dir.clone()));
+        }
+    }
+
+    let mut models = Vec::with_capacity(data.meshes.len());
+    for mesh in data.meshes {
+        let material = match mesh.material {
+            Some(ref name) => match materials.get(name) {
+                // Submeshes sharing a material share its textures too
+                Some((m, lib_dir)) => loaded.entry(name.clone()).or_insert_with(|| m.to_material(lib_dir)).clone(),
+                None => {
+                    println!("Material \"{}\" not found for mesh \"{}\"!", name, mesh.name);
+                    Material::default()
+                }
+            },
+            None => Material::default(),
+        };
+        models.push(Model::new(mesh.vertices, mesh.indices).with_material(material));
+    }
+    Ok(models)
+}
+
+#[derive(Clone, Copy)]
+struct Corner {
+    position: usize,
+    texcoord: Option<usize>,
+    normal: Option<usize>,
+}
+
+struct Face {
+    corners: Vec<Corner>,
+    smoothing_group: u32,
+}
+
+struct Group {
+    name: String,
+    material: Option<String>,
+    faces: Vec<Face>,
+}
+
+// Identifies an output vertex. Vertices without an explicit normal are shared
+// inside their smoothing group, or not at all when smoothing is off.
+#[derive(PartialEq, Eq, Hash)]
+enum VertexKey {
+    Explicit(usize, Option<usize>, usize),
+    Smooth(usize, Option<usize>, u32),
+    Flat(usize, Option<usize>, usize),
+}
+
+pub fn parse_obj(source: &str) -> Result<ObjData, String> {
+    let mut positions: Vec<Vector3<f32>> = Vec::new();
+    let mut texcoords: Vec<Vector2<f32>> = Vec::new();
+    let mut normals: Vec<Vector3<f32>> = Vec::new();
+    let mut material_libs = Vec::new();
+
+    let mut groups = vec![Group { name: String::from("default"), material: None, faces: Vec::new() }];
+    let mut smoothing_group = 0;
+
+    for (line_nr, line) in logical_lines(source) {
+        let mut tokens = line.split_whitespace();
+        let keyword = match tokens.next() {
+            Some(k) => k,
+            None => continue,
+        };
+        let args: Vec<&str> = tokens.collect();
+        let err = |msg: &str| format!("line {}: {}", line_nr, msg);
+
+        match keyword {
+            "v" => positions.push(parse_vec3(&args).map_err(|e| err(&e))?),
+            "vn" => normals.push(parse_vec3(&args).map_err(|e| err(&e))?),
+            "vt" => {
+                if args.is_empty() {
+                    return Err(err("vt needs at least one coordinate"));
+                }
+                let v = if args.len() > 1 { parse_float(args.get(1)).map_err(|e| err(&e))? } else { 0.0 };
+                texcoords.push(Vector2::new(parse_float(args.first()).map_err(|e| err(&e))?, v));
+            },
+            "f" => {
+                if args.len() < 3 {
+                    return Err(err("face needs at least three vertices"));
+                }
+                let mut corners = Vec::with_capacity(args.len());
+                for arg in args.iter() {
+                    corners.push(
+                        parse_corner(arg, positions.len(), texcoords.len(), normals.len())
+                            .map_err(|e| err(&e))?
+                    );
+                }
+                groups.last_mut().unwrap().faces.push(Face { corners, smoothing_group });
+            },
+            "o" | "g" => {
+                let name = if args.is_empty() { String::from("default") } else { args.join(" ") };
+                let material = groups.last().unwrap().material.clone();
+                start_group(&mut groups, name, material);
+            },
+            "usemtl" => {
+                if args.is_empty() {
+                    return Err(err("usemtl needs a material name"));
+                }
+                let name = groups.last().unwrap().name.clone();
+                start_group(&mut groups, name, Some(args.join(" ")));
+            },
+            "mtllib" => material_libs.extend(args.iter().map(|s| s.to_string())),
+            "s" => {
+                smoothing_group = match args.first() {
+                    Some(&"off") | None => 0,
+                    Some(&"on") => 1,
+                    Some(s) => s.parse().map_err(|_| err(&format!("invalid smoothing group \"{}\"", s)))?,
+                };
+            },
+            // Lines, points, curves etc. are not supported
+            _ => {}
+        }
+    }
+
+    // Smoothing groups can span several groups, so accumulate normals over every face first
+    let mut smooth_normals: HashMap<(u32, usize), Vector3<f32>> = HashMap::new();
+    for group in groups.iter() {
+        for face in group.faces.iter() {
+            if face.smoothing_group == 0 {
+                continue;
+            }
+            let normal = face_normal(face, &positions);
+            for corner in face.corners.iter() {
+                *smooth_normals
+                    .entry((face.smoothing_group, corner.position))
+                    .or_insert(Vector3::zero()) += normal;
+            }
+        }
+    }
+
+    let mut meshes = Vec::new();
+    let mut face_id = 0;
+    for group in groups {
+        if group.faces.is_empty() {
+            continue;
+        }
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        let mut vertex_lookup: HashMap<VertexKey, u32> = HashMap::new();
+
+        for face in group.faces.iter() {
+            let flat_normal = face_normal(face, &positions);
+            let mut face_indices = Vec::with_capacity(face.corners.len());
+
+            for corner in face.corners.iter() {
+                let (key, normal) = match corner.normal {
+                    Some(n) => (VertexKey::Explicit(corner.position, corner.texcoord, n), normals[n]),
+                    None if face.smoothing_group != 0 => (
+                        VertexKey::Smooth(corner.position, corner.texcoord, face.smoothing_group),
+                        smooth_normals[&(face.smoothing_group, corner.position)]
+                    ),
+                    None => (VertexKey::Flat(corner.position, corner.texcoord, face_id), flat_normal),
+                };
+                let next_index = (vertices.len() / 8) as u32;
+                let index = *vertex_lookup.entry(key).or_insert_with(|| {
+                    let position = positions[corner.position];
+                    let normal = if normal.magnitude2() > 0.0 { normal.normalize() } else { normal };
+                    let uv = corner.texcoord.map(|t| texcoords[t]).unwrap_or(Vector2::zero());
+                    vertices.extend_from_slice(&[
+                        position.x, position.y, position.z,
+                        normal.x, normal.y, normal.z,
+                        uv.x, uv.y,
+                    ]);
+                    next_index
+                });
+                face_indices.push(index);
+            }
+
+            // Fan triangulation, works for the convex polygons exporters write
+            for i in 1..face_indices.len() - 1 {
+                indices.push(face_indices[0]);
+                indices.push(face_indices[i]);
+                indices.push(face_indices[i + 1]);
+            }
+            face_id += 1;
+        }
+
+        meshes.push(ObjMesh {
+            name: group.name,
+            material: group.material,
+            vertices,
+            indices,
+        });
+    }
+
+    Ok(ObjData { meshes, material_libs })
+}
+
+pub fn parse_mtl(source: &str) -> Result<Vec<MtlMaterial>, String> {
+    let mut materials: Vec<MtlMaterial> = Vec::new();
+
+    for (line_nr, line) in logical_lines(source) {
+        let mut tokens = line.split_whitespace();
+        let keyword = match tokens.next() {
+            Some(k) => k,
+            None => continue,
+        };
+        let args: Vec<&str> = tokens.collect();
+        let err = |msg: &str| format!("line {}: {}", line_nr, msg);
+
+        if keyword == "newmtl" {
+            if args.is_empty() {
+                return Err(err("newmtl needs a material name"));
+            }
+            materials.push(MtlMaterial::new(&args.join(" ")));
+            continue;
+        }
+
+        let material = match materials.last_mut() {
+            Some(m) => m,
+            None => return Err(err(&format!("\"{}\" before any newmtl", keyword))),
+        };
+        match keyword {
+            "Ka" => material.ambient = parse_vec3(&args).map_err(|e| err(&e))?,
+            "Kd" => material.diffuse = parse_vec3(&args).map_err(|e| err(&e))?,
+            "Ks" => material.specular = parse_vec3(&args).map_err(|e| err(&e))?,
+            "Ke" => material.emission = parse_vec3(&args).map_err(|e| err(&e))?,
+            "Ns" => material.shininess = parse_float(args.first()).map_err(|e| err(&e))?,
+            "d" => material.dissolve = parse_float(args.first()).map_err(|e| err(&e))?,
+            "Tr" => material.dissolve = 1.0 - parse_float(args.first()).map_err(|e| err(&e))?,
+            "illum" => {
+                material.illum = args.first()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| err("invalid illum"))?;
+            },
+            "map_Kd" => material.diffuse_map = map_file(&args),
+            "map_Ks" => material.specular_map = map_file(&args),
+            "map_Ke" => material.emission_map = map_file(&args),
+            "map_Bump" | "map_bump" | "bump" | "norm" => material.normal_map = map_file(&args),
+            _ => {}
+        }
+    }
+    Ok(materials)
+}
+
+// Starts a new group unless the current one is still empty
+fn start_group(groups: &mut Vec<Group>, name: String, material: Option<String>) {
+    let current = groups.last_mut().unwrap();
+    if current.faces.is_empty() {
+        current.name = name;
+        current.material = material;
+    } else {
+        groups.push(Group { name, material, faces: Vec::new() });
+    }
+}
+
+// Yields lines with comments removed and '\' continuations joined, with 1-based line numbers
+fn logical_lines(source: &str) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut pending = String::new();
+    let mut start = 0;
+    for (i, line) in source.lines().enumerate() {
+        if pending.is_empty() {
+            start = i + 1;
+        }
+        let line = match line.find('#') {
+            Some(pos) => &line[..pos],
+            None => line,
+        };
+        let trimmed = line.trim_end();
+        if let Some(joined) = trimmed.strip_suffix('\\') {
+            pending.push_str(joined);
+            pending.push(' ');
+            continue;
+        }
+        pending.push_str(trimmed);
+        lines.push((start, std::mem::take(&mut pending)));
+    }
+    if !pending.is_empty() {
+        lines.push((start, pending));
+    }
+    lines
+}
+
+fn load_map(dir: &Path, file: &Option<String>) -> Option<Rc<Texture2D>> {
+    let path = dir.join(file.as_ref()?);
+    match Texture2D::from_file(&path.to_string_lossy()) {
+        Ok(texture) => Some(Rc::new(texture)),
+        Err(e) => {
+            println!("{}", e);
+            None
+        }
+    }
+}
+
+// Options like "-bm 1.0" come before the file name, the rest is the file
+// name even when it has spaces
+fn map_file(args: &[&str]) -> Option<String> {
+    let mut rest = args;
+    while let Some(option) = rest.first().filter(|arg| arg.starts_with('-')) {
+        let values = match *option {
+            "-mm" => 2,
+            // -o, -s and -t take 1 to 3 numbers
+            "-o" | "-s" | "-t" => rest[1..].iter().take(3).take_while(|arg| arg.parse::<f32>().is_ok()).count(),
+            _ => 1,
+        };
+        rest = &rest[(1 + values).min(rest.len())..];
+    }
+    if rest.is_empty() { None } else { Some(rest.join(" ")) }
+}
+
+fn parse_float(arg: Option<&&str>) -> Result<f32, String> {
+    match arg {
+        Some(s) => s.parse().map_err(|_| format!("invalid number \"{}\"", s)),
+        None => Err(String::from("missing number")),
+    }
+}
+
+fn parse_vec3(args: &[&str]) -> Result<Vector3<f32>, String> {
+    if args.len() < 3 {
+        return Err(format!("expected 3 numbers, got {}", args.len()));
+    }
+    Ok(Vector3 {
+        x: parse_float(args.first())?,
+        y: parse_float(args.get(1))?,
+        z: parse_float(args.get(2))?,
+    })
+}
+
+// Parses "v", "v/vt", "v//vn" or "v/vt/vn" into zero-based indices
+fn parse_corner(arg: &str, positions: usize, texcoords: usize, normals: usize) -> Result<Corner, String> {
+    let mut parts = arg.split('/');
+    let position = match parts.next() {
+        Some(p) if !p.is_empty() => resolve_index(p, positions)?,
+        _ => return Err(format!("invalid face vertex \"{}\"", arg)),
+    };
+    let texcoord = match parts.next() {
+        Some(t) if !t.is_empty() => Some(resolve_index(t, texcoords)?),
+        _ => None,
+    };
+    let normal = match parts.next() {
+        Some(n) if !n.is_empty() => Some(resolve_index(n, normals)?),
+        _ => None,
+    };
+    Ok(Corner { position, texcoord, normal })
+}
+
+// Obj indices are 1-based, negative ones count back from the latest element
+fn resolve_index(arg: &str, len: usize) -> Result<usize, String> {
+    let index: i64 = arg.parse().map_err(|_| format!("invalid index \"{}\"", arg))?;
+    let resolved = if index > 0 { index - 1 } else { len as i64 + index };
+    if index == 0 || resolved < 0 || resolved >= len as i64 {
+        return Err(format!("index {} out of range", index));
+    }
+    Ok(resolved as usize)
+}
+
+// Newell's method, handles non-planar and concave polygons gracefully
+fn face_normal(face: &Face, positions: &[Vector3<f32>]) -> Vector3<f32> {
+    let mut normal = Vector3::zero();
+    for i in 0..face.corners.len() {
+        let current = positions[face.corners[i].position];
+        let next = positions[face.corners[(i + 1) % face.corners.len()].position];
+        normal.x += (current.y - next.y) * (current.z + next.z);
+        normal.y += (current.z - next.z) * (current.x + next.x);
+        normal.z += (current.x - next.x) * (current.y + next.y);
+    }
+    if normal.magnitude2() > 0.0 { normal.normalize() } else { normal }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Position, normal and uv of each vertex of a mesh
+    fn vertices(mesh: &ObjMesh) -> Vec<&[f32]> {
+        mesh.vertices.chunks(8).collect()
+    }
+
+    fn assert_near(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn resolves_negative_indices() {
+        let data = parse_obj("v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0.5 0.25\nf -3/-1 -2/-1 -1/-1\n").unwrap();
+        let mesh = &data.meshes[0];
+        assert_eq!(mesh.indices, vec![0, 1, 2]);
+        assert_near(vertices(mesh)[1], &[1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn triangulates_quads_and_ngons_as_fans() {
+        let source = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nv -1 0.5 0\nf 1 2 3 4\nf 1 2 3 4 5\n";
+        let mesh = &parse_obj(source).unwrap().meshes[0];
+        // Flat faces don't share vertices, the pentagon starts at vertex 4
+        assert_eq!(mesh.indices, vec![
+            0, 1, 2, 0, 2, 3,
+            4, 5, 6, 4, 6, 7, 4, 7, 8,
+        ]);
+        assert_eq!(vertices(mesh).len(), 9);
+    }
+
+    #[test]
+    fn reads_position_normal_corners() {
+        let source = "v 0 0 0\nv 1 0 0\nv 0 1 0\nvn 0 0 -1\nf 1//1 2//1 3//1\nf 3//1 2//1 1//1\n";
+        let mesh = &parse_obj(source).unwrap().meshes[0];
+        // Explicit normals are shared by every face using the same corner
+        assert_eq!(mesh.indices, vec![0, 1, 2, 2, 1, 0]);
+        for vertex in vertices(mesh) {
+            assert_near(&vertex[3..], &[0.0, 0.0, -1.0, 0.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn averages_normals_in_smoothing_groups() {
+        // Two faces folded along the shared edge between vertices 1 and 2
+        let source = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 1 0 -1\ns 1\nf 1 2 3\nf 2 4 3\n";
+        let mesh = &parse_obj(source).unwrap().meshes[0];
+        assert_eq!(mesh.indices, vec![0, 1, 2, 1, 3, 2]);
+        let vertices = vertices(mesh);
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        assert_near(&vertices[0][3..6], &[0.0, 0.0, 1.0]);
+        assert_near(&vertices[1][3..6], &[half, 0.0, half]);
+        assert_near(&vertices[3][3..6], &[1.0, 0.0, 0.0]);
+
+        // Smoothing off gives every face its own vertices
+        let flat = parse_obj(&source.replace("s 1", "s off")).unwrap();
+        assert_eq!(flat.meshes[0].indices, vec![0, 1, 2, 3, 4, 5]);
+        // "s on" is the same as "s 1"
+        let on = parse_obj(&source.replace("s 1", "s on")).unwrap();
+        assert_eq!(on.meshes[0].indices, mesh.indices);
+    }
+
+    #[test]
+    fn joins_continued_lines() {
+        let source = "v 0 0 \\\n 0\nv 1 0 0 # comment \\\nv 0 1 0\nf 1 \\\n2 \\\n3\ng second\nf 3 2 1\n";
+        let data = parse_obj(source).unwrap();
+        assert_eq!(data.meshes.len(), 2);
+        assert_eq!(data.meshes[0].indices, vec![0, 1, 2]);
+        assert_eq!(data.meshes[1].name, "second");
+
+        let err = parse_obj("v 0 0 0\nv 1 0 0\nv 0 1 \\\n0\nf 1 2 \\\n 9\n").unwrap_err();
+        assert_eq!(err, "line 5: index 9 out of range");
+    }
+
+    #[test]
+    fn splits_groups_and_materials() {
+        let source = "mtllib a.mtl b.mtl\nv 0 0 0\nv 1 0 0\nv 0 1 0\no cube\nusemtl red\nf 1 2 3\nusemtl blue\nf 1 2 3\n";
+        let data = parse_obj(source).unwrap();
+        assert_eq!(data.material_libs, vec!["a.mtl", "b.mtl"]);
+        let meshes: Vec<_> = data.meshes.iter().map(|m| (m.name.as_str(), m.material.as_deref())).collect();
+        assert_eq!(meshes, vec![("cube", Some("red")), ("cube", Some("blue"))]);
+    }
+
+    #[test]
+    fn rejects_out_of_range_indices() {
+        let source = "v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nvn 0 0 1\n";
+        let err = |face: &str| parse_obj(&format!("{}{}\n", source, face)).unwrap_err();
+        assert_eq!(err("f 1 2 4"), "line 6: index 4 out of range");
+        assert_eq!(err("f 1 2 0"), "line 6: index 0 out of range");
+        assert_eq!(err("f 1 2 -4"), "line 6: index -4 out of range");
+        assert_eq!(err("f 1/2 2 3"), "line 6: index 2 out of range");
+        assert_eq!(err("f 1//2 2 3"), "line 6: index 2 out of range");
+        assert_eq!(err("f 1 2"), "line 6: face needs at least three vertices");
+        assert_eq!(err("s smooth"), "line 6: invalid smoothing group \"smooth\"");
+    }
+
+    #[test]
+    fn parses_mtl_fields() {
+        let source = "\
+# two materials
+newmtl shiny metal
+Ka 0.1 0.2 0.3
+Kd 0.4 0.5 0.6
+Ks 1 1 1
+Ke 0 0.5 0
+Ns 64
+d 0.75
+illum 3
+map_Kd -o 0.5 0.5 -bm 1 textures/shiny metal.png
+map_Ks spec.png
+map_Bump -bm 0.5 normal.png
+map_Ke -clamp on glow.png
+
+newmtl glass
+Tr 0.25
+";
+        let materials = parse_mtl(source).unwrap();
+        assert_eq!(materials.len(), 2);
+        let shiny = &materials[0];
+        assert_eq!(shiny.name, "shiny metal");
+        assert_eq!(shiny.ambient, Vector3::new(0.1, 0.2, 0.3));
+        assert_eq!(shiny.diffuse, Vector3::new(0.4, 0.5, 0.6));
+        assert_eq!(shiny.specular, Vector3::new(1.0, 1.0, 1.0));
+        assert_eq!(shiny.emission, Vector3::new(0.0, 0.5, 0.0));
+        assert_eq!(shiny.shininess, 64.0);
+        assert_eq!(shiny.dissolve, 0.75);
+        assert_eq!(shiny.illum, 3);
+        assert_eq!(shiny.diffuse_map.as_deref(), Some("textures/shiny metal.png"));
+        assert_eq!(shiny.specular_map.as_deref(), Some("spec.png"));
+        assert_eq!(shiny.normal_map.as_deref(), Some("normal.png"));
+        assert_eq!(shiny.emission_map.as_deref(), Some("glow.png"));
+
+        let glass = &materials[1];
+        assert_eq!(glass.dissolve, 0.75);
+        assert_eq!(glass.diffuse_map, None);
+    }
+
+    #[test]
+    fn rejects_broken_mtl() {
+        assert_eq!(parse_mtl("Kd 1 1 1\n").unwrap_err(), "line 1: \"Kd\" before any newmtl");
+        assert_eq!(parse_mtl("newmtl a\nKd 1 1\n").unwrap_err(), "line 2: expected 3 numbers, got 2");
+        assert_eq!(parse_mtl("newmtl a\nillum x\n").unwrap_err(), "line 2: invalid illum");
+        assert_eq!(map_file(&["-bm", "1"]), None);
+    }
+}
@@ -0,0 +1,199 @@
+// Records every frame of a run at a fixed timestep, as numbered PNGs or as
+// an uncompressed Y4M video that ffmpeg can encode later:
+//   ffmpeg -i run.y4m -c:v libx264 -pix_fmt yuv420p run.mp4
+// Frames are read into a ring of pixel buffer objects and written a few
+// frames later, so reading back doesn't stall on the frame just drawn.
+use gl;
+use std;
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use stb_image;
+
+use render_gl;
+use render_gl::{Buffer, BufferTarget, Usage};
+
+// Frames in flight before the oldest one is waited for
+const BUFFERS: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    // frame-00000.png... in a directory
+    Png,
+    // 4:4:4 BT.601, one file
+    Y4m,
+}
+
+impl Format {
+    // .y4m files are video, anything else is a directory of PNGs
+    pub fn from_path(path: &Path) -> Format {
+        match path.extension() {
+            Some(extension) if extension.eq_ignore_ascii_case("y4m") => Format::Y4m,
+            _ => Format::Png,
+        }
+    }
+}
+
+enum Output {
+    Png(PathBuf),
+    Y4m(BufWriter<File>),
+}
+
+pub struct Recorder {
+    output: Output,
+    width: u32,
+    height: u32,
+    fps: u32,
+    buffers: Vec<Buffer>,
+    // Frame numbers read into the buffers but not written yet, oldest first.
+    // Frame n is in buffers[n % BUFFERS].
+    in_flight: VecDeque<u32>,
+    next_frame: u32,
+}
+
+impl Recorder {
+    // Frames are captured at the given size from the bound read framebuffer,
+    // so the window shouldn't be resized while recording
+    pub fn new(path: &Path, width: u32, height: u32, fps: u32) -> Result<Self, String> {
+        let output = match Format::from_path(path) {
+            Format::Png => {
+                fs::create_dir_all(path).map_err(|err| format!("Couldn't create {}: {}", path.display(), err))?;
+                Output::Png(path.to_path_buf())
+            },
+            Format::Y4m => {
+                let file = File::create(path).map_err(|err| format!("Couldn't create {}: {}", path.display(), err))?;
+                let mut file = BufWriter::new(file);
+                writeln!(file, "YUV4MPEG2 W{} H{} F{}:1 Ip A1:1 C444", width, height, fps)
+                    .map_err(|err| format!("Couldn't write {}: {}", path.display(), err))?;
+                Output::Y4m(file)
+            },
+        };
+
+        let buffers = (0..BUFFERS).map(|_| {
+            let mut buffer = Buffer::new(BufferTarget::PixelPack, Usage::StreamRead);
+            buffer.reserve(width as usize * height as usize * 4);
+            buffer.unbind();
+            buffer
+        }).collect();
+
+        Ok(Recorder { output, width, height, fps: fps.max(1), buffers, in_flight: VecDeque::new(), next_frame: 0 })
+    }
+
+    // Scene time of the next frame in seconds, independent of how long frames take
+    pub fn time(&self) -> f32 {
+        self.next_frame as f32 / self.fps as f32
+    }
+
+    // Seconds between frames
+    pub fn timestep(&self) -> f32 {
+        1.0 / self.fps as f32
+    }
+
+    pub fn frames(&self) -> u32 {
+        self.next_frame
+    }
+
+    // Starts reading the frame that was just drawn, call it before swapping
+    pub fn capture(&mut self) -> Result<(), String> {
+        if self.in_flight.len() == BUFFERS {
+            self.write_oldest()?;
+        }
+        let buffer = &self.buffers[self.next_frame as usize % BUFFERS];
+        buffer.bind();
+        unsafe {
+            gl::ReadPixels(0, 0, self.width as i32, self.height as i32, gl::RGBA, gl::UNSIGNED_BYTE, std::ptr::null_mut());
+        }
+        buffer.unbind();
+        self.in_flight.push_back(self.next_frame);
+        self.next_frame += 1;
+        Ok(())
+    }
+
+    // Writes the frames still in flight, returns how many were recorded
+    pub fn finish(mut self) -> Result<u32, String> {
+        while !self.in_flight.is_empty() {
+            self.write_oldest()?;
+        }
+        if let Output::Y4m(ref mut file) = self.output {
+            file.flush().map_err(|err| format!("Couldn't write the video: {}", err))?;
+        }
+        Ok(self.next_frame)
+    }
+
+    fn write_oldest(&mut self) -> Result<(), String> {
+        let frame = match self.in_flight.pop_front() {
+            Some(frame) => frame,
+            None => return Ok(()),
+        };
+        let size = self.width as usize * self.height as usize * 4;
+        let mut rgba = vec![0u8; size];
+        let buffer = &self.buffers[frame as usize % BUFFERS];
+        buffer.bind();
+        unsafe {
+            // Waits for the read to land if it hasn't yet
+            let mapped = gl::MapBufferRange(gl::PIXEL_PACK_BUFFER, 0, size as gl::types::GLsizeiptr, gl::MAP_READ_BIT);
+            if !mapped.is_null() {
+                std::ptr::copy_nonoverlapping(mapped as *const u8, rgba.as_mut_ptr(), size);
+                gl::UnmapBuffer(gl::PIXEL_PACK_BUFFER);
+            }
+            buffer.unbind();
+            if mapped.is_null() {
+                return Err(format!("Couldn't map the pixels of frame {}", frame));
+            }
+        }
+        render_gl::flip_rows(&mut rgba, self.width as usize * 4);
+
+        match self.output {
+            Output::Png(ref directory) => {
+                let rgb: Vec<u8> = rgba.chunks(4).flat_map(|pixel| pixel[..3].iter().cloned()).collect();
+                let path = directory.join(format!("frame-{:05}.png", frame));
+                stb_image::write_png(&path.to_string_lossy(), self.width, self.height, 3, &rgb)
+                    .map_err(|err| err.to_string())
+            },
+            Output::Y4m(ref mut file) => {
+                let (y, u, v) = to_yuv444(&rgba);
+                file.write_all(b"FRAME\n")
+                    .and_then(|()| file.write_all(&y))
+                    .and_then(|()| file.write_all(&u))
+                    .and_then(|()| file.write_all(&v))
+                    .map_err(|err| format!("Couldn't write frame {}: {}", frame, err))
+            },
+        }
+    }
+}
+
+// Splits RGBA into limited range BT.601 planes, what Y4M players assume
+fn to_yuv444(rgba: &[u8]) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+    let pixels = rgba.len() / 4;
+    let (mut y, mut u, mut v) = (Vec::with_capacity(pixels), Vec::with_capacity(pixels), Vec::with_capacity(pixels));
+    for pixel in rgba.chunks(4) {
+        let (r, g, b) = (pixel[0] as i32, pixel[1] as i32, pixel[2] as i32);
+        y.push((((66 * r + 129 * g + 25 * b + 128) >> 8) + 16) as u8);
+        u.push((((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128) as u8);
+        v.push((((112 * r - 94 * g - 18 * b + 128) >> 8) + 128) as u8);
+    }
+    (y, u, v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_to_studio_range_yuv() {
+        // Black, white, red, green and blue with alpha ignored
+        let rgba = [
+            0, 0, 0, 255,
+            255, 255, 255, 0,
+            255, 0, 0, 255,
+            0, 255, 0, 255,
+            0, 0, 255, 255,
+        ];
+        let (y, u, v) = to_yuv444(&rgba);
+        assert_eq!(y, vec![16, 235, 82, 144, 41]);
+        assert_eq!(u, vec![128, 128, 90, 54, 240]);
+        assert_eq!(v, vec![128, 128, 240, 34, 110]);
+    }
+}
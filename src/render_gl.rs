@@ -1,17 +1,54 @@
 use gl;
 use std;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
 use std::ffi::CString;
 
+pub use self::buffer::{Buffer, BufferTarget, Usage, VertexArray};
+pub use self::error::{ShaderError, Stage};
+pub use self::framebuffer::{ColorFormat, DepthFormat, DepthStorage, Framebuffer};
+pub use self::preprocess::{Preprocessor, Preprocessed};
+pub use self::uniform::{Uniform, UniformElement};
+#[macro_use]
+pub mod debug;
+pub mod buffer;
+pub mod error;
+pub mod framebuffer;
+pub mod gl4;
+pub mod preprocess;
+#[macro_use]
+pub mod vertex;
+mod uniform;
+
+thread_local! {
+    // Program last bound with set_used on this thread's context
+    static CURRENT_PROGRAM: Cell<gl::types::GLuint> = const { Cell::new(0) };
+}
+
+// An active uniform as reflected after linking
+#[derive(Clone, Copy, Debug)]
+pub struct UniformInfo {
+    pub location: gl::types::GLint,
+    pub gl_type: gl::types::GLenum,
+    // Array length, 1 for non-arrays
+    pub size: gl::types::GLint,
+}
+
 pub struct Program {
     id: gl::types::GLuint,
+    uniforms: HashMap<String, UniformInfo>,
+    warned: RefCell<HashSet<String>>,
 }
 
 impl Program {
-    pub fn from_shaders(vertex_source: &str, frag_source: &str) -> Result<Self, String> {
-        let v_shader = Shader::from_vert_source(vertex_source).unwrap();
-        let f_shader = Shader::from_frag_source(frag_source).unwrap();
-        let shaders = [v_shader, f_shader];
+    pub fn from_shaders(vertex_source: &str, frag_source: &str) -> Result<Self, ShaderError> {
+        ProgramBuilder::new()
+            .vertex(vertex_source)
+            .fragment(frag_source)
+            .build()
+    }
 
+    pub fn link(shaders: &[Shader]) -> Result<Self, ShaderError> {
         let program_id = unsafe { gl::CreateProgram() };
         for shader in shaders.iter() {
             unsafe {
@@ -29,23 +66,11 @@ impl Program {
         }
 
         if success == 0 {
-            let mut len: gl::types::GLint = 0;
-            unsafe {
-                gl::GetProgramiv(program_id, gl::INFO_LOG_LENGTH, &mut len);
-            }
-
-            let error = create_cstring_with_len(len as usize);
-
+            let log = info_log(program_id, gl::GetProgramiv, gl::GetProgramInfoLog);
             unsafe {
-                gl::GetProgramInfoLog(
-                    program_id,
-                    len,
-                    std::ptr::null_mut(),
-                    error.as_ptr() as *mut gl::types::GLchar,
-                );
+                gl::DeleteProgram(program_id);
             }
-
-            return Err(format!("Error linking program: {}", error.to_string_lossy().into_owned()));
+            return Err(ShaderError::Link { log });
         }
 
         for shader in shaders.iter() {
@@ -54,91 +79,257 @@ impl Program {
             }
         }
 
-        Ok(Program { id: program_id })
+        Ok(Program {
+            id: program_id,
+            uniforms: reflect_uniforms(program_id),
+            warned: RefCell::new(HashSet::new()),
+        })
     }
 
     pub fn id(&self) -> gl::types::GLuint {
         self.id
     }
 
+    // Names the program in debug output and captures
+    pub fn set_label(&self, name: &str) {
+        debug::label(debug::Object::Program, self.id, name);
+    }
+
     pub fn set_used(&self) {
         unsafe {
             gl::UseProgram(self.id);
         }
+        CURRENT_PROGRAM.with(|current| current.set(self.id));
     }
 
-    pub fn set_bool(&self, name: &str, value: bool) {
-        unsafe {
-            let uniform_loc = gl::GetUniformLocation(self.id, CString::new(name).unwrap().as_bytes_with_nul().as_ptr() as *const gl::types::GLchar);
-            if uniform_loc == -1 {
-                println!("Couldn't find uniform location: \"{}\"!", name);
+    // Sets a uniform by name, binding the program if it isn't already.
+    // Unknown names and mismatched types are reported once and otherwise ignored.
+    pub fn set_uniform<T: Uniform>(&self, name: &str, value: T) {
+        let info = match self.uniforms.get(name) {
+            Some(info) => info,
+            None => {
+                self.warn_once(name, || format!("Couldn't find uniform location: \"{}\"!", name));
+                return;
             }
-            self.set_used();
-            gl::Uniform1i(uniform_loc, value as gl::types::GLint);
+        };
+        if !T::Element::accepts(info.gl_type) {
+            self.warn_once(name, || format!("Uniform \"{}\" is a {}, can't set it from {}!",
+                name, uniform::type_name(info.gl_type), std::any::type_name::<T>()));
+            return;
         }
-    }
-    pub fn set_int(&self, name: &str, value: i32) {
+        let elements = value.elements();
+        if elements.len() > info.size as usize {
+            self.warn_once(name, || format!("Uniform \"{}\" holds {} values, got {}!", name, info.size, elements.len()));
+            return;
+        }
+        self.bind();
         unsafe {
-            let uniform_loc = gl::GetUniformLocation(self.id, CString::new(name).unwrap().as_bytes_with_nul().as_ptr() as *const gl::types::GLchar);
-            if uniform_loc == -1 {
-                println!("Couldn't find uniform location: \"{}\"!", name);
-            }
+            T::Element::upload(elements, info.location);
+        }
+    }
+
+    pub fn has_uniform(&self, name: &str) -> bool {
+        self.uniforms.contains_key(name)
+    }
+
+    pub fn uniforms(&self) -> &HashMap<String, UniformInfo> {
+        &self.uniforms
+    }
+
+    // Skips glUseProgram when this program is already in use
+    fn bind(&self) {
+        if CURRENT_PROGRAM.with(|current| current.get()) != self.id {
             self.set_used();
-            gl::Uniform1i(uniform_loc, value as gl::types::GLint);
         }
     }
-    pub fn set_float(&self, name: &str, value: f32) {
+
+    fn warn_once<F: FnOnce() -> String>(&self, name: &str, message: F) {
+        if self.warned.borrow_mut().insert(name.to_string()) {
+            println!("{}", message());
+        }
+    }
+}
+
+impl Drop for Program {
+    fn drop(&mut self) {
+        CURRENT_PROGRAM.with(|current| if current.get() == self.id { current.set(0) });
         unsafe {
-            let uniform_loc = gl::GetUniformLocation(self.id, CString::new(name).unwrap().as_bytes_with_nul().as_ptr() as *const gl::types::GLchar);
-            if uniform_loc == -1 {
-                println!("Couldn't find uniform location: \"{}\"!", name);
-            }
-            self.set_used();
-            gl::Uniform1f(uniform_loc, value as gl::types::GLfloat);
+            gl::DeleteProgram(self.id);
+        }
+    }
+}
+// Collects any combination of stages for a Program. Stages the context
+// doesn't support fail in build with ShaderError::Unsupported.
+pub struct ProgramBuilder {
+    stages: Vec<(gl::types::GLenum, String)>,
+    shaders: Vec<Shader>,
+}
+
+impl ProgramBuilder {
+    pub fn new() -> Self {
+        ProgramBuilder { stages: Vec::new(), shaders: Vec::new() }
+    }
+
+    pub fn vertex(self, source: &str) -> Self { self.stage(gl::VERTEX_SHADER, source) }
+    pub fn tess_control(self, source: &str) -> Self { self.stage(gl4::TESS_CONTROL_SHADER, source) }
+    pub fn tess_evaluation(self, source: &str) -> Self { self.stage(gl4::TESS_EVALUATION_SHADER, source) }
+    #[cfg(not(feature = "gles30"))]
+    pub fn geometry(self, source: &str) -> Self { self.stage(gl::GEOMETRY_SHADER, source) }
+    pub fn fragment(self, source: &str) -> Self { self.stage(gl::FRAGMENT_SHADER, source) }
+
+    pub fn stage(mut self, kind: gl::types::GLenum, source: &str) -> Self {
+        self.stages.push((kind, source.to_string()));
+        self
+    }
+
+    // Attaches an already compiled shader, e.g. one built from preprocessed source
+    pub fn shader(mut self, shader: Shader) -> Self {
+        self.shaders.push(shader);
+        self
+    }
+
+    pub fn build(self) -> Result<Program, ShaderError> {
+        let mut shaders = self.shaders;
+        for &(kind, ref source) in self.stages.iter() {
+            shaders.push(Shader::from_source(source, kind)?);
         }
+        Program::link(&shaders)
     }
-    pub fn set_mat4(&self, name: &str, value: *const gl::types::GLfloat) {
+}
+
+// A program with a single compute stage, needs a 4.3 context
+#[cfg(not(feature = "gles30"))]
+pub struct ComputeProgram {
+    program: Program,
+    // The local_size_x/y/z declared in the shader
+    work_group_size: [i32; 3],
+    // How many work groups the driver allows per dispatch in x, y and z
+    max_work_group_count: [u32; 3],
+}
+
+#[cfg(not(feature = "gles30"))]
+impl ComputeProgram {
+    pub fn from_source(source: &str) -> Result<Self, ShaderError> {
+        ComputeProgram::from_shader(Shader::from_source(source, gl4::COMPUTE_SHADER)?)
+    }
+
+    pub fn from_shader(shader: Shader) -> Result<Self, ShaderError> {
+        let program = Program::link(&[shader])?;
+        let mut work_group_size = [0; 3];
         unsafe {
-            let uniform_loc = gl::GetUniformLocation(self.id, CString::new(name).unwrap().as_bytes_with_nul().as_ptr() as *const gl::types::GLchar);
-            if uniform_loc == -1 {
-                println!("Couldn't find uniform location: \"{}\"!", name);
+            gl::GetProgramiv(program.id(), gl4::COMPUTE_WORK_GROUP_SIZE, work_group_size.as_mut_ptr());
+        }
+        let mut max_work_group_count = [0; 3];
+        for (i, max) in max_work_group_count.iter_mut().enumerate() {
+            let mut value = 0;
+            unsafe {
+                gl::GetIntegeri_v(gl4::MAX_COMPUTE_WORK_GROUP_COUNT, i as gl::types::GLuint, &mut value);
             }
-            self.set_used();
-            gl::UniformMatrix4fv(uniform_loc, 1, gl::FALSE, value);
+            *max = value as u32;
         }
+        Ok(ComputeProgram { program, work_group_size, max_work_group_count })
+    }
+
+    // For setting uniforms
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn work_group_size(&self) -> [i32; 3] {
+        self.work_group_size
     }
-    pub fn set_vec3(&self, name: &str, v0: f32, v1: f32, v2: f32) {
+
+    pub fn max_work_group_count(&self) -> [u32; 3] {
+        self.max_work_group_count
+    }
+
+    // Runs x * y * z work groups. Results written to buffers or images are only
+    // visible to later commands after a matching memory_barrier.
+    pub fn dispatch(&self, x: u32, y: u32, z: u32) -> Result<(), String> {
+        let max = self.max_work_group_count;
+        if x > max[0] || y > max[1] || z > max[2] {
+            return Err(format!("Compute dispatch ({}, {}, {}) is over the limit ({}, {}, {})", x, y, z, max[0], max[1], max[2]));
+        }
+        self.program.set_used();
         unsafe {
-            let uniform_loc = gl::GetUniformLocation(self.id, CString::new(name).unwrap().as_bytes_with_nul().as_ptr() as *const gl::types::GLchar);
-            if uniform_loc == -1 {
-                println!("Couldn't find uniform location: \"{}\"!", name);
-            }
-            self.set_used();
-            gl::Uniform3f(uniform_loc, v0, v1, v2);
+            gl::DispatchCompute(x, y, z);
         }
+        Ok(())
     }
-}
 
-impl Drop for Program {
-    fn drop(&mut self) {
+    // Bits are the gl4::*_BARRIER_BIT constants
+    pub fn memory_barrier(barriers: gl::types::GLbitfield) {
         unsafe {
-            gl::DeleteProgram(self.id);
+            gl::MemoryBarrier(barriers);
         }
     }
 }
+
+// Vertices per patch for drawing with gl4::PATCHES, needs a 4.0 context
+#[cfg(not(feature = "gles30"))]
+pub fn set_patch_vertices(count: i32) {
+    unsafe {
+        gl::PatchParameteri(gl4::PATCH_VERTICES, count);
+    }
+}
+
+// Reads 8 bit RGB (3 channels) or RGBA (4) pixels from the bound read
+// framebuffer. gl returns the bottom row first, the rows are flipped so the
+// result is top row first like image files.
+pub fn read_pixels(x: i32, y: i32, width: u32, height: u32, channels: i32) -> Vec<u8> {
+    let format = match channels {
+        3 => gl::RGB,
+        4 => gl::RGBA,
+        n => panic!("Can only read 3 or 4 channels, not {}", n),
+    };
+    // GLES only has to support reading RGBA, RGB is read as RGBA and packed after
+    let (format, read_channels) = if gl::TARGET_GLES { (gl::RGBA, 4) } else { (format, channels as usize) };
+    let stride = width as usize * channels as usize;
+    let mut pixels = vec![0u8; width as usize * read_channels * height as usize];
+    unsafe {
+        // RGB rows aren't padded to 4 bytes
+        gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+        gl::ReadPixels(x, y, width as i32, height as i32, format, gl::UNSIGNED_BYTE, pixels.as_mut_ptr() as *mut gl::types::GLvoid);
+        gl::PixelStorei(gl::PACK_ALIGNMENT, 4);
+    }
+    if read_channels != channels as usize {
+        pixels = pixels.chunks(read_channels).flat_map(|pixel| pixel[..channels as usize].iter().cloned()).collect();
+    }
+    flip_rows(&mut pixels, stride);
+    pixels
+}
+
+// Swaps the image upside down in place
+pub fn flip_rows(pixels: &mut [u8], stride: usize) {
+    let rows = pixels.len() / stride.max(1);
+    for row in 0..rows / 2 {
+        let (top, bottom) = pixels.split_at_mut((rows - row - 1) * stride);
+        top[row * stride..(row + 1) * stride].swap_with_slice(&mut bottom[..stride]);
+    }
+}
+
 pub struct Shader {
     id: gl::types::GLuint,
 }
 
 impl Shader {
-    pub fn from_source(source: &str, kind: gl::types::GLuint) -> Result<Shader, String> {
+    pub fn from_source(source: &str, kind: gl::types::GLuint) -> Result<Shader, ShaderError> {
+        let name = format!("<{} source>", Stage::from_kind(kind));
+        Shader::from_named_source(&name, source, kind)
+    }
+    // The name is only used in error messages, usually the file the source came from
+    pub fn from_named_source(name: &str, source: &str, kind: gl::types::GLuint) -> Result<Shader, ShaderError> {
+        Shader::from_preprocessed(&Preprocessed::unprocessed(name, source), kind)
+    }
+    // Errors are reported against the files that went into the source
+    pub fn from_preprocessed(source: &Preprocessed, kind: gl::types::GLuint) -> Result<Shader, ShaderError> {
         shader_from_source(source, kind).map(|i| Shader { id: i })
     }
-    pub fn from_vert_source(source: &str) -> Result<Shader, String> {
-        Shader::from_source(source, gl::VERTEX_SHADER).map_err(|err| format!("Vertex shader: {}", err))
+    pub fn from_vert_source(source: &str) -> Result<Shader, ShaderError> {
+        Shader::from_source(source, gl::VERTEX_SHADER)
     }
-    pub fn from_frag_source(source: &str) -> Result<Shader, String> {
-        Shader::from_source(source, gl::FRAGMENT_SHADER).map_err(|err| format!("Fragment shader: {}", err))
+    pub fn from_frag_source(source: &str) -> Result<Shader, ShaderError> {
+        Shader::from_source(source, gl::FRAGMENT_SHADER)
     }
 
     pub fn id(&self) -> gl::types::GLuint {
@@ -154,11 +345,18 @@ impl Drop for Shader {
     }
 }
 
-fn shader_from_source(source: &str, kind: gl::types::GLuint) -> Result<gl::types::GLuint, String> {
-    let source = CString::new(source).map_err(|_| "Source included NUL character")?;
+fn shader_from_source(source: &Preprocessed, kind: gl::types::GLuint) -> Result<gl::types::GLuint, ShaderError> {
+    let stage = Stage::from_kind(kind);
+    let available = gl4::context_version();
+    if available < stage.required_version() {
+        return Err(ShaderError::Unsupported { stage, required: stage.required_version(), available });
+    }
+    let c_source = CString::new(source.source.as_str()).map_err(|_| ShaderError::InvalidSource {
+        source_name: source.files.first().map(|file| file.name.clone()).unwrap_or_default(),
+    })?;
     let id = unsafe { gl::CreateShader(kind) };
     unsafe {
-        gl::ShaderSource(id, 1, &source.as_ptr(), std::ptr::null());
+        gl::ShaderSource(id, 1, &c_source.as_ptr(), std::ptr::null());
         gl::CompileShader(id);
     }
 
@@ -168,29 +366,77 @@ fn shader_from_source(source: &str, kind: gl::types::GLuint) -> Result<gl::types
         gl::GetShaderiv(id, gl::COMPILE_STATUS, &mut success);
     }
     if success == 0 {
-        let mut len: gl::types::GLint = 0;
+        let log = info_log(id, gl::GetShaderiv, gl::GetShaderInfoLog);
         unsafe {
-            gl::GetShaderiv(id, gl::INFO_LOG_LENGTH, &mut len);
+            gl::DeleteShader(id);
         }
+        return Err(ShaderError::compile(stage, &source.files, log));
+    }
+    Ok(id)
+}
+
+// Reads a shader or program info log with the matching pair of gl functions
+fn info_log(
+    id: gl::types::GLuint,
+    get_iv: unsafe fn(gl::types::GLuint, gl::types::GLenum, *mut gl::types::GLint),
+    get_log: unsafe fn(gl::types::GLuint, gl::types::GLsizei, *mut gl::types::GLsizei, *mut gl::types::GLchar),
+) -> String {
+    let mut len: gl::types::GLint = 0;
+    unsafe {
+        get_iv(id, gl::INFO_LOG_LENGTH, &mut len);
+    }
+    let mut buffer = vec![0u8; len.max(1) as usize];
+    let mut written: gl::types::GLsizei = 0;
+    unsafe {
+        get_log(id, buffer.len() as gl::types::GLsizei, &mut written, buffer.as_mut_ptr() as *mut gl::types::GLchar);
+    }
+    buffer.truncate(written.max(0) as usize);
+    String::from_utf8_lossy(&buffer).into_owned()
+}
 
-        let error = create_cstring_with_len(len as usize);
+// Collects every active uniform. Arrays of basic types are reported once as
+// "name[0]", so each element is added as "name[i]" and the whole array as "name".
+fn reflect_uniforms(program_id: gl::types::GLuint) -> HashMap<String, UniformInfo> {
+    let mut uniforms = HashMap::new();
+    let (mut count, mut max_len) = (0, 0);
+    unsafe {
+        gl::GetProgramiv(program_id, gl::ACTIVE_UNIFORMS, &mut count);
+        gl::GetProgramiv(program_id, gl::ACTIVE_UNIFORM_MAX_LENGTH, &mut max_len);
+    }
+
+    for i in 0..count as gl::types::GLuint {
+        let mut buffer = vec![0u8; max_len.max(1) as usize];
+        let (mut len, mut size, mut gl_type) = (0, 0, 0);
         unsafe {
-            gl::GetShaderInfoLog(
-                id,
-                len,
-                std::ptr::null_mut(),
-                error.as_ptr() as *mut gl::types::GLchar,
+            gl::GetActiveUniform(
+                program_id, i, buffer.len() as gl::types::GLsizei,
+                &mut len, &mut size, &mut gl_type,
+                buffer.as_mut_ptr() as *mut gl::types::GLchar
             );
         }
-        return Err(format!("Shader error on: {}", error.to_string_lossy()));
+        buffer.truncate(len as usize);
+        let name = String::from_utf8_lossy(&buffer).into_owned();
+
+        // Uniforms in blocks have no location
+        let location = uniform_location(program_id, &name);
+        if location == -1 {
+            continue;
+        }
+        if name.ends_with("[0]") {
+            let base = &name[..name.len() - 3];
+            uniforms.insert(base.to_string(), UniformInfo { location, gl_type, size });
+            for element in 1..size {
+                let element_name = format!("{}[{}]", base, element);
+                let location = uniform_location(program_id, &element_name);
+                uniforms.insert(element_name, UniformInfo { location, gl_type, size: size - element });
+            }
+        }
+        uniforms.insert(name, UniformInfo { location, gl_type, size });
     }
-    Ok(id)
+    uniforms
 }
 
-fn create_cstring_with_len(len: usize) -> CString {
-    let mut buffer: Vec<u8> = Vec::with_capacity(len as usize + 1);
-    unsafe {
-        buffer.set_len(len as usize + 1);
-    }
-    unsafe { CString::from_vec_unchecked(buffer) }
+fn uniform_location(program_id: gl::types::GLuint, name: &str) -> gl::types::GLint {
+    let name = CString::new(name).unwrap();
+    unsafe { gl::GetUniformLocation(program_id, name.as_ptr()) }
 }
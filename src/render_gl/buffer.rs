@@ -0,0 +1,439 @@
+// Owned buffer and vertex array objects. They delete their gl names when
+// dropped and can't be sent to other threads, where the names mean nothing.
+// Data that changes every frame goes in a RingBuffer, or a Buffer that's
+// orphaned before each rewrite.
+use gl;
+use std;
+use std::marker::PhantomData;
+
+use render_gl::debug;
+use render_gl::gl4;
+use render_gl::vertex::VertexLayout;
+
+use gl::types::{GLbitfield, GLenum, GLuint};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BufferTarget {
+    Array,
+    // Binding one changes the bound vertex array's index buffer, so Buffer
+    // unbinds the vertex array first. Attach one with VertexArray::set_index_buffer.
+    ElementArray,
+    Uniform,
+    PixelPack,
+    PixelUnpack,
+}
+
+impl BufferTarget {
+    pub fn to_gl(self) -> GLenum {
+        match self {
+            BufferTarget::Array => gl::ARRAY_BUFFER,
+            BufferTarget::ElementArray => gl::ELEMENT_ARRAY_BUFFER,
+            BufferTarget::Uniform => gl::UNIFORM_BUFFER,
+            BufferTarget::PixelPack => gl::PIXEL_PACK_BUFFER,
+            BufferTarget::PixelUnpack => gl::PIXEL_UNPACK_BUFFER,
+        }
+    }
+}
+
+// How often the contents change and who reads them, a hint for where the
+// driver keeps the buffer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Usage {
+    // Written once, drawn many times
+    StaticDraw,
+    // Rewritten now and then, drawn many times
+    DynamicDraw,
+    // Rewritten about every time it's drawn, like particles and debug lines
+    StreamDraw,
+    StaticRead,
+    DynamicRead,
+    // Filled by the gpu and read back once, like pixel readback
+    StreamRead,
+    StaticCopy,
+    DynamicCopy,
+    StreamCopy,
+}
+
+impl Usage {
+    pub fn to_gl(self) -> GLenum {
+        match self {
+            Usage::StaticDraw => gl::STATIC_DRAW,
+            Usage::DynamicDraw => gl::DYNAMIC_DRAW,
+            Usage::StreamDraw => gl::STREAM_DRAW,
+            Usage::StaticRead => gl::STATIC_READ,
+            Usage::DynamicRead => gl::DYNAMIC_READ,
+            Usage::StreamRead => gl::STREAM_READ,
+            Usage::StaticCopy => gl::STATIC_COPY,
+            Usage::DynamicCopy => gl::DYNAMIC_COPY,
+            Usage::StreamCopy => gl::STREAM_COPY,
+        }
+    }
+}
+
+pub struct Buffer {
+    id: GLuint,
+    target: BufferTarget,
+    usage: Usage,
+    // Bytes allocated
+    size: usize,
+    // Immutable storage keeps its size and can only be written through a
+    // mapping, the methods that would change it panic
+    immutable: bool,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Buffer {
+    // An empty buffer, allocate it with set_data or reserve
+    pub fn new(target: BufferTarget, usage: Usage) -> Self {
+        let mut id = 0;
+        unsafe {
+            gl::GenBuffers(1, &mut id);
+        }
+        Buffer { id, target, usage, size: 0, immutable: false, _not_send: PhantomData }
+    }
+
+    pub fn from_data<T: Copy>(target: BufferTarget, usage: Usage, data: &[T]) -> Self {
+        let mut buffer = Buffer::new(target, usage);
+        buffer.set_data(data);
+        buffer
+    }
+
+    pub fn id(&self) -> GLuint { self.id }
+    pub fn target(&self) -> BufferTarget { self.target }
+    pub fn usage(&self) -> Usage { self.usage }
+    pub fn size(&self) -> usize { self.size }
+    pub fn is_immutable(&self) -> bool { self.immutable }
+
+    // Names the buffer in debug output and captures, once it's been bound
+    pub fn set_label(&self, name: &str) {
+        debug::label(debug::Object::Buffer, self.id, name);
+    }
+
+    // Every other method that touches the contents binds through here
+    pub fn bind(&self) {
+        self.leave_vertex_arrays();
+        unsafe {
+            gl::BindBuffer(self.target.to_gl(), self.id);
+        }
+    }
+
+    pub fn unbind(&self) {
+        self.leave_vertex_arrays();
+        unsafe {
+            gl::BindBuffer(self.target.to_gl(), 0);
+        }
+    }
+
+    // The index buffer binding is part of the vertex array, so an index
+    // buffer bound with one bound would replace or detach its indices
+    fn leave_vertex_arrays(&self) {
+        if self.target == BufferTarget::ElementArray {
+            VertexArray::unbind();
+        }
+    }
+
+    // Replaces the contents and size, leaves the buffer bound
+    pub fn set_data<T: Copy>(&mut self, data: &[T]) {
+        self.allocate(std::mem::size_of_val(data), data.as_ptr() as *const gl::types::GLvoid);
+    }
+
+    // Allocates size bytes with undefined contents, leaves the buffer bound
+    pub fn reserve(&mut self, size: usize) {
+        self.allocate(size, std::ptr::null());
+    }
+
+    // Overwrites part of the contents, offset in bytes. Waits for draws still
+    // reading the buffer, orphan it first to avoid that when rewriting all of it.
+    pub fn update_range<T: Copy>(&self, offset: usize, data: &[T]) {
+        let size = std::mem::size_of_val(data);
+        assert!(!self.immutable, "Can't update immutable buffer storage, map it instead");
+        assert!(offset + size <= self.size, "Updating {} bytes at {} overflows a {} byte buffer", size, offset, self.size);
+        self.bind();
+        unsafe {
+            gl::BufferSubData(
+                self.target.to_gl(),
+                offset as gl::types::GLintptr,
+                size as gl::types::GLsizeiptr,
+                data.as_ptr() as *const gl::types::GLvoid,
+            );
+        }
+    }
+
+    // Swaps in fresh storage of the same size so writes don't wait for draws
+    // still using the old contents, which the driver frees when they finish.
+    // The contents are undefined afterwards.
+    pub fn orphan(&self) {
+        assert!(!self.immutable, "Can't orphan immutable buffer storage");
+        self.bind();
+        unsafe {
+            gl::BufferData(self.target.to_gl(), self.size as gl::types::GLsizeiptr, std::ptr::null(), self.usage.to_gl());
+        }
+    }
+
+    // Orphans and writes data from the start, growing the buffer if it doesn't fit
+    pub fn rewrite<T: Copy>(&mut self, data: &[T]) {
+        if std::mem::size_of_val(data) > self.size {
+            self.set_data(data);
+        } else {
+            self.orphan();
+            self.update_range(0, data);
+        }
+    }
+
+    fn allocate(&mut self, size: usize, data: *const gl::types::GLvoid) {
+        assert!(!self.immutable, "Can't reallocate immutable buffer storage");
+        self.bind();
+        unsafe {
+            gl::BufferData(self.target.to_gl(), size as gl::types::GLsizeiptr, data, self.usage.to_gl());
+        }
+        self.size = size;
+    }
+
+    // Allocates immutable storage with the glBufferStorage flags, where the
+    // driver has buffer storage. Leaves the buffer bound if it did.
+    #[cfg(not(feature = "gles30"))]
+    fn allocate_storage(&mut self, size: usize, flags: GLbitfield) -> bool {
+        if self.immutable || !gl4::has_buffer_storage() {
+            return false;
+        }
+        self.bind();
+        unsafe {
+            gl::BufferStorage(self.target.to_gl(), size as gl::types::GLsizeiptr, std::ptr::null(), flags);
+        }
+        self.size = size;
+        self.immutable = true;
+        true
+    }
+
+    #[cfg(feature = "gles30")]
+    fn allocate_storage(&mut self, _size: usize, _flags: GLbitfield) -> bool {
+        false
+    }
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteBuffers(1, &self.id);
+        }
+    }
+}
+
+// A buffer split into sections written by the cpu one frame at a time
+// while the gpu reads the ones before. Each section is fenced when the frame
+// is done with it, and only waited on when the ring comes back around to it.
+// The storage is mapped once and kept mapped where the driver has buffer
+// storage, otherwise each write maps its range unsynchronized.
+pub struct RingBuffer {
+    buffer: Buffer,
+    // Null when each write maps its own range
+    mapped: *mut u8,
+    section_size: usize,
+    sections: usize,
+    current: usize,
+    // Bytes written to the current section
+    written: usize,
+    // Offsets returned by write are multiples of this
+    alignment: usize,
+    // Null for sections the gpu isn't using
+    fences: Vec<gl::types::GLsync>,
+}
+
+impl RingBuffer {
+    // Three sections lets the cpu run two frames ahead of the gpu
+    pub fn new(target: BufferTarget, section_size: usize, sections: usize) -> Self {
+        let sections = sections.max(1);
+        let alignment = match target {
+            BufferTarget::Uniform => {
+                let mut alignment = 0;
+                unsafe {
+                    gl::GetIntegerv(gl::UNIFORM_BUFFER_OFFSET_ALIGNMENT, &mut alignment);
+                }
+                alignment.max(16) as usize
+            },
+            _ => 16,
+        };
+        let section_size = align(section_size, alignment);
+        let size = section_size * sections;
+
+        let mut buffer = Buffer::new(target, Usage::StreamDraw);
+        let mut mapped = std::ptr::null_mut();
+        let flags = gl::MAP_WRITE_BIT | gl4::MAP_PERSISTENT_BIT | gl4::MAP_COHERENT_BIT;
+        if buffer.allocate_storage(size, flags) {
+            // Writes map their range if this fails
+            unsafe {
+                mapped = gl::MapBufferRange(target.to_gl(), 0, size as gl::types::GLsizeiptr, flags) as *mut u8;
+            }
+        } else {
+            buffer.reserve(size);
+        }
+        buffer.unbind();
+
+        RingBuffer {
+            buffer,
+            mapped,
+            section_size,
+            sections,
+            current: 0,
+            written: 0,
+            alignment,
+            fences: vec![std::ptr::null(); sections],
+        }
+    }
+
+    // To bind for drawing, with the offsets write returns. The storage is
+    // immutable when the driver has buffer storage, so it can't be orphaned
+    // or updated through the Buffer.
+    pub fn buffer(&self) -> &Buffer { &self.buffer }
+    pub fn section_size(&self) -> usize { self.section_size }
+
+    pub fn is_persistent(&self) -> bool {
+        !self.mapped.is_null()
+    }
+
+    // Copies data into the current section and returns its byte offset in
+    // the buffer, or None when the section is too full for it
+    pub fn write<T: Copy>(&mut self, data: &[T]) -> Option<usize> {
+        let size = std::mem::size_of_val(data);
+        let start = align(self.written, self.alignment);
+        if start + size > self.section_size {
+            return None;
+        }
+        let offset = self.current * self.section_size + start;
+        unsafe {
+            if self.is_persistent() {
+                std::ptr::copy_nonoverlapping(data.as_ptr() as *const u8, self.mapped.add(offset), size);
+            } else if size > 0 {
+                let target = self.buffer.target.to_gl();
+                // The fences already keep the gpu off this range
+                let access = gl::MAP_WRITE_BIT | gl::MAP_UNSYNCHRONIZED_BIT | gl::MAP_INVALIDATE_RANGE_BIT;
+                self.buffer.bind();
+                let pointer = gl::MapBufferRange(target, offset as gl::types::GLintptr, size as gl::types::GLsizeiptr, access);
+                if pointer.is_null() {
+                    return None;
+                }
+                std::ptr::copy_nonoverlapping(data.as_ptr() as *const u8, pointer as *mut u8, size);
+                gl::UnmapBuffer(target);
+            }
+        }
+        self.written = start + size;
+        Some(offset)
+    }
+
+    // Call once the draws reading this frame's writes have been issued. Fences
+    // the section and moves to the next, waiting for the gpu if it's still
+    // reading that one.
+    pub fn next_frame(&mut self) {
+        unsafe {
+            self.fences[self.current] = gl::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0);
+        }
+        self.current = (self.current + 1) % self.sections;
+        self.written = 0;
+        wait_and_delete(&mut self.fences[self.current]);
+    }
+}
+
+impl Drop for RingBuffer {
+    fn drop(&mut self) {
+        for fence in self.fences.iter_mut() {
+            if !fence.is_null() {
+                unsafe {
+                    gl::DeleteSync(*fence);
+                }
+                *fence = std::ptr::null();
+            }
+        }
+        if self.is_persistent() {
+            self.buffer.bind();
+            unsafe {
+                gl::UnmapBuffer(self.buffer.target.to_gl());
+            }
+            self.buffer.unbind();
+        }
+    }
+}
+
+// Blocks until the gpu has passed the fence, then deletes it
+fn wait_and_delete(fence: &mut gl::types::GLsync) {
+    if fence.is_null() {
+        return;
+    }
+    unsafe {
+        // Flush on the first wait so the fence is sure to be reached
+        let mut flags = gl::SYNC_FLUSH_COMMANDS_BIT;
+        loop {
+            match gl::ClientWaitSync(*fence, flags, 1_000_000) {
+                gl::ALREADY_SIGNALED | gl::CONDITION_SATISFIED | gl::WAIT_FAILED => break,
+                _ => flags = 0,
+            }
+        }
+        gl::DeleteSync(*fence);
+    }
+    *fence = std::ptr::null();
+}
+
+fn align(value: usize, alignment: usize) -> usize {
+    value.div_ceil(alignment) * alignment
+}
+
+// The methods that change it leave no vertex array bound, so buffers bound
+// afterwards can't end up in it by accident
+pub struct VertexArray {
+    id: GLuint,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl VertexArray {
+    pub fn new() -> Self {
+        let mut id = 0;
+        unsafe {
+            gl::GenVertexArrays(1, &mut id);
+        }
+        VertexArray { id, _not_send: PhantomData }
+    }
+
+    pub fn id(&self) -> GLuint { self.id }
+
+    // Names the vertex array in debug output and captures, once it's been bound
+    pub fn set_label(&self, name: &str) {
+        debug::label(debug::Object::VertexArray, self.id, name);
+    }
+
+    pub fn bind(&self) {
+        unsafe {
+            gl::BindVertexArray(self.id);
+        }
+    }
+
+    pub fn unbind() {
+        unsafe {
+            gl::BindVertexArray(0);
+        }
+    }
+
+    // Reads the layout's attributes from vertices, starting offset bytes in
+    pub fn set_vertex_buffer(&self, vertices: &Buffer, layout: &VertexLayout, offset: usize) {
+        self.bind();
+        unsafe {
+            gl::BindBuffer(gl::ARRAY_BUFFER, vertices.id);
+        }
+        layout.apply(offset);
+        VertexArray::unbind();
+    }
+
+    pub fn set_index_buffer(&self, indices: &Buffer) {
+        self.bind();
+        unsafe {
+            gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, indices.id);
+        }
+        VertexArray::unbind();
+    }
+}
+
+impl Drop for VertexArray {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteVertexArrays(1, &self.id);
+        }
+    }
+}
@@ -0,0 +1,368 @@
+// Error reporting for gl calls. With the debug-output feature and a context
+// that has KHR_debug, DebugOutput::install routes driver messages to a sink.
+// Otherwise gl_check! polls glGetError after a call.
+// Object labels and debug groups show up in tools like RenderDoc, they do
+// nothing without the feature.
+use gl;
+use std;
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+use std::fmt;
+use std::marker::PhantomData;
+
+// Runs a gl call and reports any glGetError codes it left, with the call site.
+// Evaluates to the call's result.
+macro_rules! gl_check {
+    ($call:expr) => {{
+        let result = $call;
+        $crate::render_gl::debug::check_error(file!(), line!(), stringify!($call));
+        result
+    }};
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Source {
+    Api,
+    WindowSystem,
+    ShaderCompiler,
+    ThirdParty,
+    Application,
+    Other,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageType {
+    Error,
+    DeprecatedBehavior,
+    UndefinedBehavior,
+    Portability,
+    Performance,
+    Marker,
+    PushGroup,
+    PopGroup,
+    Other,
+}
+
+// Ordered from least to most severe
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Notification,
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Clone, Debug)]
+pub struct DebugMessage {
+    pub source: Source,
+    pub message_type: MessageType,
+    pub id: u32,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl fmt::Display for DebugMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[GL {:?} {:?} {:?} #{}] {}", self.severity, self.source, self.message_type, self.id, self.message.trim_end())
+    }
+}
+
+thread_local! {
+    // Set while a callback is installed, so gl_check! doesn't report errors twice
+    static CALLBACK_INSTALLED: Cell<bool> = const { Cell::new(false) };
+}
+
+struct State {
+    sink: Box<dyn FnMut(&DebugMessage)>,
+    ignored_ids: HashSet<u32>,
+    min_severity: Severity,
+    panic_on_high: bool,
+}
+
+// Keeps the callback installed until dropped
+pub struct DebugOutput {
+    // Boxed so the pointer handed to gl stays put
+    state: Box<RefCell<State>>,
+}
+
+impl DebugOutput {
+    // Installs the callback with a sink that prints every message. Returns None
+    // when the crate was built without debug-output or the context lacks KHR_debug.
+    // In test builds high severity messages panic, which aborts since the callback
+    // can't unwind, but output is synchronous so the backtrace shows the bad call.
+    pub fn install() -> Option<DebugOutput> {
+        DebugOutput::with_sink(|message| println!("{}", message))
+    }
+
+    pub fn with_sink<F: FnMut(&DebugMessage) + 'static>(sink: F) -> Option<DebugOutput> {
+        let state = Box::new(RefCell::new(State {
+            sink: Box::new(sink),
+            ignored_ids: HashSet::new(),
+            min_severity: Severity::Notification,
+            panic_on_high: cfg!(test),
+        }));
+        if !install_callback(&*state as *const RefCell<State> as *const std::os::raw::c_void) {
+            return None;
+        }
+        CALLBACK_INSTALLED.with(|installed| installed.set(true));
+        Some(DebugOutput { state })
+    }
+
+    // Drops messages with this id, e.g. the driver's buffer usage notifications
+    pub fn ignore_id(&self, id: u32) {
+        self.state.borrow_mut().ignored_ids.insert(id);
+    }
+
+    pub fn allow_id(&self, id: u32) {
+        self.state.borrow_mut().ignored_ids.remove(&id);
+    }
+
+    // Drops messages below the severity
+    pub fn set_min_severity(&self, severity: Severity) {
+        self.state.borrow_mut().min_severity = severity;
+    }
+
+    pub fn set_panic_on_high(&self, panic_on_high: bool) {
+        self.state.borrow_mut().panic_on_high = panic_on_high;
+    }
+}
+
+impl Drop for DebugOutput {
+    fn drop(&mut self) {
+        uninstall_callback();
+        CALLBACK_INSTALLED.with(|installed| installed.set(false));
+    }
+}
+
+#[cfg(feature = "debug-output")]
+fn install_callback(state: *const std::os::raw::c_void) -> bool {
+    if !gl::DebugMessageCallback::is_loaded() {
+        println!("Debug output isn't available, the context has no KHR_debug");
+        return false;
+    }
+    unsafe {
+        gl::Enable(gl::DEBUG_OUTPUT);
+        // Report from inside the offending call instead of some time later
+        gl::Enable(gl::DEBUG_OUTPUT_SYNCHRONOUS);
+        // DebugGroup pushes every frame, don't echo those back
+        for &gltype in [gl::DEBUG_TYPE_PUSH_GROUP, gl::DEBUG_TYPE_POP_GROUP].iter() {
+            gl::DebugMessageControl(gl::DONT_CARE, gltype, gl::DONT_CARE, 0, std::ptr::null(), gl::FALSE);
+        }
+        gl::DebugMessageCallback(callback, state);
+    }
+    true
+}
+
+#[cfg(not(feature = "debug-output"))]
+fn install_callback(_state: *const std::os::raw::c_void) -> bool {
+    println!("Debug output isn't available, build with --features debug-output");
+    false
+}
+
+#[cfg(feature = "debug-output")]
+fn uninstall_callback() {
+    unsafe {
+        gl::Disable(gl::DEBUG_OUTPUT);
+        gl::DebugMessageCallback(callback, std::ptr::null());
+    }
+}
+
+#[cfg(not(feature = "debug-output"))]
+fn uninstall_callback() {}
+
+#[cfg(feature = "debug-output")]
+extern "system" fn callback(
+    source: gl::types::GLenum,
+    gltype: gl::types::GLenum,
+    id: gl::types::GLuint,
+    severity: gl::types::GLenum,
+    length: gl::types::GLsizei,
+    message: *const gl::types::GLchar,
+    user_param: *mut std::os::raw::c_void,
+) {
+    if user_param.is_null() {
+        return;
+    }
+    let state = unsafe { &*(user_param as *const RefCell<State>) };
+    // A message raised from inside the sink can't be reported
+    let mut state = match state.try_borrow_mut() {
+        Ok(state) => state,
+        Err(_) => return,
+    };
+
+    let severity = match severity {
+        gl::DEBUG_SEVERITY_HIGH => Severity::High,
+        gl::DEBUG_SEVERITY_MEDIUM => Severity::Medium,
+        gl::DEBUG_SEVERITY_LOW => Severity::Low,
+        _ => Severity::Notification,
+    };
+    if severity < state.min_severity || state.ignored_ids.contains(&id) {
+        return;
+    }
+
+    let bytes = unsafe {
+        if length < 0 {
+            std::ffi::CStr::from_ptr(message).to_bytes()
+        } else {
+            std::slice::from_raw_parts(message as *const u8, length as usize)
+        }
+    };
+    let message = DebugMessage {
+        source: match source {
+            gl::DEBUG_SOURCE_API => Source::Api,
+            gl::DEBUG_SOURCE_WINDOW_SYSTEM => Source::WindowSystem,
+            gl::DEBUG_SOURCE_SHADER_COMPILER => Source::ShaderCompiler,
+            gl::DEBUG_SOURCE_THIRD_PARTY => Source::ThirdParty,
+            gl::DEBUG_SOURCE_APPLICATION => Source::Application,
+            _ => Source::Other,
+        },
+        message_type: match gltype {
+            gl::DEBUG_TYPE_ERROR => MessageType::Error,
+            gl::DEBUG_TYPE_DEPRECATED_BEHAVIOR => MessageType::DeprecatedBehavior,
+            gl::DEBUG_TYPE_UNDEFINED_BEHAVIOR => MessageType::UndefinedBehavior,
+            gl::DEBUG_TYPE_PORTABILITY => MessageType::Portability,
+            gl::DEBUG_TYPE_PERFORMANCE => MessageType::Performance,
+            gl::DEBUG_TYPE_MARKER => MessageType::Marker,
+            gl::DEBUG_TYPE_PUSH_GROUP => MessageType::PushGroup,
+            gl::DEBUG_TYPE_POP_GROUP => MessageType::PopGroup,
+            _ => MessageType::Other,
+        },
+        id,
+        severity,
+        message: String::from_utf8_lossy(bytes).into_owned(),
+    };
+
+    (state.sink)(&message);
+    if severity == Severity::High && state.panic_on_high {
+        panic!("High severity GL message: {}", message);
+    }
+}
+
+// What kind of gl name a label is for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Object {
+    Buffer,
+    Shader,
+    Program,
+    VertexArray,
+    Texture,
+    Framebuffer,
+    Renderbuffer,
+}
+
+#[cfg(feature = "debug-output")]
+impl Object {
+    fn identifier(self) -> gl::types::GLenum {
+        match self {
+            Object::Buffer => gl::BUFFER,
+            Object::Shader => gl::SHADER,
+            Object::Program => gl::PROGRAM,
+            Object::VertexArray => gl::VERTEX_ARRAY,
+            Object::Texture => gl::TEXTURE,
+            Object::Framebuffer => gl::FRAMEBUFFER,
+            Object::Renderbuffer => gl::RENDERBUFFER,
+        }
+    }
+}
+
+// Names the object in debug messages and captures. Buffers and vertex arrays
+// only count as created once they've been bound, so label them after that.
+#[cfg(feature = "debug-output")]
+pub fn label(object: Object, id: gl::types::GLuint, name: &str) {
+    if !gl::ObjectLabel::is_loaded() {
+        return;
+    }
+    unsafe {
+        let mut max_length = 0;
+        gl::GetIntegerv(gl::MAX_LABEL_LENGTH, &mut max_length);
+        let length = name.len().min(max_length.max(0) as usize);
+        gl::ObjectLabel(object.identifier(), id, length as gl::types::GLsizei, name.as_ptr() as *const gl::types::GLchar);
+    }
+}
+
+#[cfg(not(feature = "debug-output"))]
+pub fn label(_object: Object, _id: gl::types::GLuint, _name: &str) {}
+
+// Groups the gl calls made while it's alive under a name, like "Draw cube".
+// Groups nest, and must be dropped on the thread that pushed them.
+pub struct DebugGroup {
+    pushed: bool,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl DebugGroup {
+    pub fn push(name: &str) -> DebugGroup {
+        DebugGroup { pushed: push_group(name), _not_send: PhantomData }
+    }
+}
+
+impl Drop for DebugGroup {
+    fn drop(&mut self) {
+        if self.pushed {
+            pop_group();
+        }
+    }
+}
+
+#[cfg(feature = "debug-output")]
+fn push_group(name: &str) -> bool {
+    if !gl::PushDebugGroup::is_loaded() {
+        return false;
+    }
+    unsafe {
+        gl::PushDebugGroup(
+            gl::DEBUG_SOURCE_APPLICATION,
+            0,
+            name.len() as gl::types::GLsizei,
+            name.as_ptr() as *const gl::types::GLchar,
+        );
+    }
+    true
+}
+
+#[cfg(not(feature = "debug-output"))]
+fn push_group(_name: &str) -> bool {
+    false
+}
+
+#[cfg(feature = "debug-output")]
+fn pop_group() {
+    unsafe {
+        gl::PopDebugGroup();
+    }
+}
+
+#[cfg(not(feature = "debug-output"))]
+fn pop_group() {}
+
+pub fn error_name(error: gl::types::GLenum) -> &'static str {
+    match error {
+        gl::INVALID_ENUM => "GL_INVALID_ENUM",
+        gl::INVALID_VALUE => "GL_INVALID_VALUE",
+        gl::INVALID_OPERATION => "GL_INVALID_OPERATION",
+        gl::INVALID_FRAMEBUFFER_OPERATION => "GL_INVALID_FRAMEBUFFER_OPERATION",
+        gl::OUT_OF_MEMORY => "GL_OUT_OF_MEMORY",
+        _ => "unknown GL error",
+    }
+}
+
+// Used by gl_check!. Drains every pending error, printing each one.
+// Skipped while a debug callback is installed since it already reports them.
+pub fn check_error(file: &str, line: u32, call: &str) -> bool {
+    if CALLBACK_INSTALLED.with(|installed| installed.get()) {
+        return true;
+    }
+    let mut ok = true;
+    loop {
+        let error = unsafe { gl::GetError() };
+        if error == gl::NO_ERROR {
+            break;
+        }
+        ok = false;
+        println!("{}:{}: {} (0x{:04X}) after {}", file, line, error_name(error), error, call);
+        if cfg!(test) {
+            panic!("{} after {}", error_name(error), call);
+        }
+    }
+    ok
+}
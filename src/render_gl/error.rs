@@ -0,0 +1,405 @@
+use gl;
+use std;
+use std::fmt;
+
+use super::gl4;
+use super::preprocess::SourceFile;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    Vertex,
+    TessControl,
+    TessEvaluation,
+    Geometry,
+    Fragment,
+    Compute,
+    Unknown(gl::types::GLenum),
+}
+
+impl Stage {
+    pub fn from_kind(kind: gl::types::GLenum) -> Stage {
+        match kind {
+            gl::VERTEX_SHADER => Stage::Vertex,
+            gl4::TESS_CONTROL_SHADER => Stage::TessControl,
+            gl4::TESS_EVALUATION_SHADER => Stage::TessEvaluation,
+            #[cfg(not(feature = "gles30"))]
+            gl::GEOMETRY_SHADER => Stage::Geometry,
+            gl::FRAGMENT_SHADER => Stage::Fragment,
+            gl4::COMPUTE_SHADER => Stage::Compute,
+            other => Stage::Unknown(other),
+        }
+    }
+
+    // Lowest core version with the stage, or the lowest ES version when the
+    // bindings are for GLES
+    pub fn required_version(&self) -> (u32, u32) {
+        if gl::TARGET_GLES {
+            return match *self {
+                Stage::TessControl | Stage::TessEvaluation | Stage::Geometry => (3, 2),
+                Stage::Compute => (3, 1),
+                _ => (3, 0),
+            };
+        }
+        match *self {
+            Stage::TessControl | Stage::TessEvaluation => (4, 0),
+            Stage::Compute => (4, 3),
+            _ => (3, 3),
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Stage::Vertex => write!(f, "vertex"),
+            Stage::TessControl => write!(f, "tessellation control"),
+            Stage::TessEvaluation => write!(f, "tessellation evaluation"),
+            Stage::Geometry => write!(f, "geometry"),
+            Stage::Fragment => write!(f, "fragment"),
+            Stage::Compute => write!(f, "compute"),
+            Stage::Unknown(kind) => write!(f, "unknown (0x{:X})", kind),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+// One message from a driver info log
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    // Source string number, which the preprocessor's #line directives use as a file index
+    pub source_string: u32,
+    // The file the source string maps to, None when it's out of range
+    pub file: Option<String>,
+    // 1-based, None when the driver didn't report a position
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+    // The line of the original source the message points at
+    pub source_line: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub enum ShaderError {
+    Compile {
+        stage: Stage,
+        source_name: String,
+        log: String,
+        diagnostics: Vec<Diagnostic>,
+    },
+    Link {
+        log: String,
+    },
+    // The source contained a NUL byte and can't be passed to gl
+    InvalidSource {
+        source_name: String,
+    },
+    // The context is older than the stage, versions are (major, minor)
+    Unsupported {
+        stage: Stage,
+        required: (u32, u32),
+        available: (u32, u32),
+    },
+    // A bad or missing #include, line is 1-based
+    Preprocess {
+        file: String,
+        line: usize,
+        message: String,
+    },
+}
+
+impl ShaderError {
+    // files are the sources the preprocessor numbered, the first one is the main file
+    pub fn compile(stage: Stage, files: &[SourceFile], log: String) -> ShaderError {
+        ShaderError::Compile {
+            stage,
+            source_name: files.first().map(|file| file.name.clone()).unwrap_or_default(),
+            diagnostics: parse_log(&log, files),
+            log,
+        }
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        match *self {
+            ShaderError::Compile { ref diagnostics, .. } => diagnostics,
+            _ => &[],
+        }
+    }
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ShaderError::Compile { stage, ref source_name, ref log, ref diagnostics } => {
+                writeln!(f, "Error compiling {} shader {}:", stage, source_name)?;
+                if diagnostics.iter().all(|d| d.line.is_none()) {
+                    return write!(f, "{}", log.trim_end());
+                }
+                for diagnostic in diagnostics.iter() {
+                    write_diagnostic(f, source_name, diagnostic)?;
+                }
+                Ok(())
+            },
+            ShaderError::Link { ref log } => write!(f, "Error linking program: {}", log.trim_end()),
+            ShaderError::InvalidSource { ref source_name } => {
+                write!(f, "Shader source {} included a NUL character", source_name)
+            },
+            ShaderError::Unsupported { stage, required, available } => write!(
+                f, "The {} stage needs OpenGL {}.{}, but the context is {}.{}",
+                stage, required.0, required.1, available.0, available.1
+            ),
+            ShaderError::Preprocess { ref file, line, ref message } => write!(f, "{}:{}: {}", file, line, message),
+        }
+    }
+}
+
+impl std::error::Error for ShaderError {}
+
+// Prints the message with the source line under it, like
+//   light.frag:12:5: error: undeclared identifier
+//      12 |     vec3 x = foo;
+//         |     ^
+fn write_diagnostic(f: &mut fmt::Formatter, source_name: &str, diagnostic: &Diagnostic) -> fmt::Result {
+    let source_name = diagnostic.file.as_ref().map_or(source_name, |file| file.as_str());
+    let severity = match diagnostic.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    };
+    match (diagnostic.line, diagnostic.column) {
+        (Some(line), Some(column)) => writeln!(f, "{}:{}:{}: {}: {}", source_name, line, column, severity, diagnostic.message)?,
+        (Some(line), None) => writeln!(f, "{}:{}: {}: {}", source_name, line, severity, diagnostic.message)?,
+        _ => return writeln!(f, "{}: {}: {}", source_name, severity, diagnostic.message),
+    }
+
+    if let (Some(line), Some(ref text)) = (diagnostic.line, diagnostic.source_line.as_ref()) {
+        let number = line.to_string();
+        writeln!(f, " {} | {}", number, text)?;
+        if let Some(column) = diagnostic.column {
+            // Keep tabs so the caret lines up with the source
+            let padding: String = text.chars().take(column.saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            writeln!(f, " {} | {}^", " ".repeat(number.len()), padding)?;
+        }
+    }
+    Ok(())
+}
+
+// Understands the common driver formats:
+//   Mesa:          0:12(5): error: ...
+//   NVIDIA:        0(12) : error C1008: ...
+//   AMD/Intel/Apple: ERROR: 0:12: ...
+// Lines that don't match are kept as diagnostics without a position.
+pub fn parse_log(log: &str, files: &[SourceFile]) -> Vec<Diagnostic> {
+    log.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut diagnostic = parse_line(line).unwrap_or_else(|| Diagnostic {
+                severity: guess_severity(line),
+                source_string: 0,
+                file: None,
+                line: None,
+                column: None,
+                message: line.to_string(),
+                source_line: None,
+            });
+            if let Some(file) = files.get(diagnostic.source_string as usize) {
+                diagnostic.file = Some(file.name.clone());
+                diagnostic.source_line = diagnostic.line
+                    .and_then(|line| file.source.lines().nth(line.wrapping_sub(1)))
+                    .map(|line| line.to_string());
+            }
+            diagnostic
+        })
+        .collect()
+}
+
+fn parse_line(line: &str) -> Option<Diagnostic> {
+    // AMD style severity prefix
+    let (prefix_severity, rest) = if let Some(rest) = strip_prefix_ignore_case(line, "error:") {
+        (Some(Severity::Error), rest.trim_start())
+    } else if let Some(rest) = strip_prefix_ignore_case(line, "warning:") {
+        (Some(Severity::Warning), rest.trim_start())
+    } else {
+        (None, line)
+    };
+
+    let (source_string, rest) = parse_number(rest)?;
+    let (line_number, column, rest) = if let Some(rest) = rest.strip_prefix(':') {
+        // 0:12: or 0:12(5):
+        let (line_number, rest) = parse_number(rest)?;
+        let (column, rest) = match rest.strip_prefix('(') {
+            Some(rest) => {
+                let (column, rest) = parse_number(rest)?;
+                (Some(column), rest.strip_prefix(')')?)
+            },
+            None => (None, rest),
+        };
+        (line_number, column, rest.trim_start().strip_prefix(':')?)
+    } else {
+        // 0(12) :
+        let rest = rest.strip_prefix('(')?;
+        let (line_number, rest) = parse_number(rest)?;
+        (line_number, None, rest.strip_prefix(')')?.trim_start().strip_prefix(':')?)
+    };
+
+    let mut message = rest.trim();
+    let mut severity = prefix_severity;
+    if severity.is_none() {
+        // "error: ...", "warning: ..." or NVIDIA's "error C1008: ..."
+        for &(word, s) in [("error", Severity::Error), ("warning", Severity::Warning)].iter() {
+            if let Some(after) = strip_prefix_ignore_case(message, word) {
+                severity = Some(s);
+                message = strip_error_code(after);
+                break;
+            }
+        }
+    }
+
+    Some(Diagnostic {
+        severity: severity.unwrap_or_else(|| guess_severity(line)),
+        source_string: source_string as u32,
+        file: None,
+        line: Some(line_number),
+        column,
+        message: message.to_string(),
+        source_line: None,
+    })
+}
+
+// Drops ": " or NVIDIA's " C1008: " left after the severity word
+fn strip_error_code(s: &str) -> &str {
+    if let Some(rest) = s.strip_prefix(':') {
+        return rest.trim();
+    }
+    let code = s.trim_start();
+    match code.find(':') {
+        Some(colon) if !code[..colon].contains(' ') => code[colon + 1..].trim(),
+        _ => s.trim(),
+    }
+}
+
+fn parse_number(s: &str) -> Option<(usize, &str)> {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    if end == 0 {
+        return None;
+    }
+    s[..end].parse().ok().map(|n| (n, &s[end..]))
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    if s.len() >= prefix.len() && s.is_char_boundary(prefix.len()) && s[..prefix.len()].eq_ignore_ascii_case(prefix) {
+        Some(&s[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+fn guess_severity(line: &str) -> Severity {
+    if line.to_ascii_lowercase().contains("warning") && !line.to_ascii_lowercase().contains("error") {
+        Severity::Warning
+    } else {
+        Severity::Error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files() -> Vec<SourceFile> {
+        vec![
+            SourceFile { name: String::from("light.frag"), source: String::from("#version 330 core\nvoid main() {\n    vec3 x = foo;\n}\n") },
+            SourceFile { name: String::from("common/lighting.glsl"), source: String::from("struct A {\n\tfloat b\n};\n") },
+        ]
+    }
+
+    fn diagnostic(severity: Severity, source_string: u32, line: usize, column: Option<usize>, message: &str) -> Diagnostic {
+        let files = files();
+        let file = &files[source_string as usize];
+        Diagnostic {
+            severity,
+            source_string,
+            file: Some(file.name.clone()),
+            line: Some(line),
+            column,
+            message: message.to_string(),
+            source_line: file.source.lines().nth(line - 1).map(|line| line.to_string()),
+        }
+    }
+
+    #[test]
+    fn parses_mesa_logs() {
+        let log = "0:3(14): error: `foo' undeclared\n1:2(9): warning: `b' used uninitialized\n";
+        assert_eq!(parse_log(log, &files()), vec![
+            diagnostic(Severity::Error, 0, 3, Some(14), "`foo' undeclared"),
+            diagnostic(Severity::Warning, 1, 2, Some(9), "`b' used uninitialized"),
+        ]);
+    }
+
+    #[test]
+    fn parses_nvidia_logs() {
+        let log = "0(3) : error C1008: undefined variable \"foo\"\n1(3) : warning C7050: \"b\" might be used before being initialized\n";
+        assert_eq!(parse_log(log, &files()), vec![
+            diagnostic(Severity::Error, 0, 3, None, "undefined variable \"foo\""),
+            diagnostic(Severity::Warning, 1, 3, None, "\"b\" might be used before being initialized"),
+        ]);
+    }
+
+    #[test]
+    fn parses_amd_logs() {
+        let log = "ERROR: 0:3: 'foo' : undeclared identifier\nWARNING: 1:2: 'b' : unused\nERROR: 1 compilation errors.  No code generated.\n";
+        let diagnostics = parse_log(log, &files());
+        assert_eq!(diagnostics[..2], [
+            diagnostic(Severity::Error, 0, 3, None, "'foo' : undeclared identifier"),
+            diagnostic(Severity::Warning, 1, 2, None, "'b' : unused"),
+        ]);
+        // The summary has no position, it stays attached to the main file
+        assert_eq!(diagnostics[2], Diagnostic {
+            severity: Severity::Error,
+            source_string: 0,
+            file: Some(String::from("light.frag")),
+            line: None,
+            column: None,
+            message: String::from("ERROR: 1 compilation errors.  No code generated."),
+            source_line: None,
+        });
+    }
+
+    #[test]
+    fn keeps_unparseable_lines() {
+        let diagnostics = parse_log("Internal compiler warning, continuing\n\n7:1(1): error: bad\n", &files());
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].line, None);
+        assert_eq!(diagnostics[0].message, "Internal compiler warning, continuing");
+        // Source strings past the preprocessed files have no file to point into
+        assert_eq!((diagnostics[1].source_string, diagnostics[1].line), (7, Some(1)));
+        assert_eq!(diagnostics[1].file, None);
+        assert_eq!(diagnostics[1].source_line, None);
+    }
+
+    #[test]
+    fn shows_the_source_line_under_the_message() {
+        let error = ShaderError::compile(Stage::Fragment, &files(), String::from("0:3(14): error: `foo' undeclared\n1:2(2): error: missing ;\n"));
+        assert_eq!(error.to_string(), "\
+Error compiling fragment shader light.frag:
+light.frag:3:14: error: `foo' undeclared
+ 3 |     vec3 x = foo;
+   |              ^
+common/lighting.glsl:2:2: error: missing ;
+ 2 | \tfloat b
+   | \t^
+");
+
+        // Logs without positions are printed as they are
+        let error = ShaderError::compile(Stage::Vertex, &files(), String::from("out of memory\n"));
+        assert_eq!(error.to_string(), "Error compiling vertex shader light.frag:\nout of memory");
+    }
+}
@@ -0,0 +1,518 @@
+// Offscreen render targets. A Framebuffer owns its color textures and its
+// depth/stencil renderbuffer or texture, reallocates them on resize and can
+// be multisampled, in which case it's resolved with a blit before use.
+use gl;
+use std;
+use std::fmt;
+use std::marker::PhantomData;
+
+use render_gl::debug;
+
+use gl::types::{GLenum, GLint, GLuint};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorFormat {
+    Rgba8,
+    // HDR color
+    Rgba16F,
+    // One float channel, for ids or depth copies
+    R32F,
+}
+
+impl ColorFormat {
+    // (internal format, format, type)
+    fn to_gl(self) -> (GLenum, GLenum, GLenum) {
+        match self {
+            ColorFormat::Rgba8 => (gl::RGBA8, gl::RGBA, gl::UNSIGNED_BYTE),
+            ColorFormat::Rgba16F => (gl::RGBA16F, gl::RGBA, gl::HALF_FLOAT),
+            ColorFormat::R32F => (gl::R32F, gl::RED, gl::FLOAT),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepthFormat {
+    Depth24Stencil8,
+    Depth24,
+    Depth32F,
+}
+
+impl DepthFormat {
+    // (internal format, format, type)
+    fn to_gl(self) -> (GLenum, GLenum, GLenum) {
+        match self {
+            DepthFormat::Depth24Stencil8 => (gl::DEPTH24_STENCIL8, gl::DEPTH_STENCIL, gl::UNSIGNED_INT_24_8),
+            DepthFormat::Depth24 => (gl::DEPTH_COMPONENT24, gl::DEPTH_COMPONENT, gl::UNSIGNED_INT),
+            DepthFormat::Depth32F => (gl::DEPTH_COMPONENT32F, gl::DEPTH_COMPONENT, gl::FLOAT),
+        }
+    }
+
+    fn attachment(self) -> GLenum {
+        match self {
+            DepthFormat::Depth24Stencil8 => gl::DEPTH_STENCIL_ATTACHMENT,
+            DepthFormat::Depth24 | DepthFormat::Depth32F => gl::DEPTH_ATTACHMENT,
+        }
+    }
+
+    fn has_stencil(self) -> bool {
+        self == DepthFormat::Depth24Stencil8
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DepthStorage {
+    // Can't be sampled, cheaper to resolve
+    Renderbuffer,
+    // Can be sampled afterwards, for shadow maps and the like
+    Texture,
+}
+
+#[derive(Debug)]
+pub enum FramebufferError {
+    TooBig { width: u32, height: u32, max: u32 },
+    TooManySamples { samples: u32, max: u32 },
+    TooManyColors { colors: usize, max: usize },
+    // attachment names the one that is incomplete on its own, None when
+    // only the combination is, like attachments with different sample counts
+    Incomplete { status: GLenum, attachment: Option<String> },
+}
+
+impl fmt::Display for FramebufferError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FramebufferError::TooBig { width, height, max } =>
+                write!(f, "{}x{} is bigger than the largest attachment, {}", width, height, max),
+            FramebufferError::TooManySamples { samples, max } =>
+                write!(f, "{} samples asked for, the driver supports up to {}", samples, max),
+            FramebufferError::TooManyColors { colors, max } =>
+                write!(f, "{} color attachments asked for, the driver supports up to {}", colors, max),
+            FramebufferError::Incomplete { status, attachment: Some(ref attachment) } =>
+                write!(f, "Framebuffer is incomplete, {} is {}", attachment, status_name(status)),
+            FramebufferError::Incomplete { status, attachment: None } =>
+                write!(f, "Framebuffer is incomplete, {}", status_name(status)),
+        }
+    }
+}
+
+fn status_name(status: GLenum) -> String {
+    let name = match status {
+        gl::FRAMEBUFFER_UNDEFINED => "GL_FRAMEBUFFER_UNDEFINED",
+        gl::FRAMEBUFFER_INCOMPLETE_ATTACHMENT => "GL_FRAMEBUFFER_INCOMPLETE_ATTACHMENT",
+        gl::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => "GL_FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT",
+        #[cfg(not(feature = "gles30"))]
+        gl::FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER => "GL_FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER",
+        #[cfg(not(feature = "gles30"))]
+        gl::FRAMEBUFFER_INCOMPLETE_READ_BUFFER => "GL_FRAMEBUFFER_INCOMPLETE_READ_BUFFER",
+        gl::FRAMEBUFFER_UNSUPPORTED => "GL_FRAMEBUFFER_UNSUPPORTED",
+        gl::FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => "GL_FRAMEBUFFER_INCOMPLETE_MULTISAMPLE",
+        #[cfg(not(feature = "gles30"))]
+        gl::FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS => "GL_FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS",
+        _ => return format!("status 0x{:X}", status),
+    };
+    String::from(name)
+}
+
+#[derive(Clone, Debug)]
+pub struct FramebufferBuilder {
+    width: u32,
+    height: u32,
+    samples: u32,
+    colors: Vec<ColorFormat>,
+    depth: Option<(DepthFormat, DepthStorage)>,
+}
+
+impl FramebufferBuilder {
+    pub fn new(width: u32, height: u32) -> Self {
+        FramebufferBuilder { width, height, samples: 1, colors: Vec::new(), depth: None }
+    }
+
+    // Adds the next color attachment, GL_COLOR_ATTACHMENT0 first
+    pub fn color(mut self, format: ColorFormat) -> Self {
+        self.colors.push(format);
+        self
+    }
+
+    pub fn depth(mut self, format: DepthFormat, storage: DepthStorage) -> Self {
+        self.depth = Some((format, storage));
+        self
+    }
+
+    // 1 for a plain framebuffer, more makes every attachment multisampled
+    pub fn samples(mut self, samples: u32) -> Self {
+        self.samples = samples.max(1);
+        self
+    }
+
+    // Leaves the bound framebuffers alone
+    pub fn build(self) -> Result<Framebuffer, FramebufferError> {
+        let mut framebuffer = Framebuffer {
+            id: 0,
+            spec: self,
+            colors: Vec::new(),
+            depth: 0,
+            label: None,
+            _not_send: PhantomData,
+        };
+        unsafe {
+            gl::GenFramebuffers(1, &mut framebuffer.id);
+        }
+        let spec = framebuffer.spec.clone();
+        framebuffer.allocate(&spec)?;
+        Ok(framebuffer)
+    }
+}
+
+pub struct Framebuffer {
+    id: GLuint,
+    spec: FramebufferBuilder,
+    // Textures, multisampled ones when samples > 1
+    colors: Vec<GLuint>,
+    // Renderbuffer or texture as the spec says, 0 without depth
+    depth: GLuint,
+    label: Option<String>,
+    // Gl names are only valid on the context's thread
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Framebuffer {
+    pub fn builder(width: u32, height: u32) -> FramebufferBuilder {
+        FramebufferBuilder::new(width, height)
+    }
+
+    pub fn id(&self) -> GLuint { self.id }
+    pub fn width(&self) -> u32 { self.spec.width }
+    pub fn height(&self) -> u32 { self.spec.height }
+    pub fn samples(&self) -> u32 { self.spec.samples }
+
+    pub fn is_multisampled(&self) -> bool {
+        self.spec.samples > 1
+    }
+
+    // A GL_TEXTURE_2D, or GL_TEXTURE_2D_MULTISAMPLE when multisampled
+    pub fn color_texture(&self, index: usize) -> Option<GLuint> {
+        self.colors.get(index).cloned()
+    }
+
+    pub fn depth_texture(&self) -> Option<GLuint> {
+        match self.spec.depth {
+            Some((_, DepthStorage::Texture)) => Some(self.depth),
+            _ => None,
+        }
+    }
+
+    // Names the framebuffer and its attachments in debug output and captures
+    pub fn set_label(&mut self, name: &str) {
+        self.label = Some(String::from(name));
+        self.apply_label();
+    }
+
+    // Binds for drawing and reading and sets the viewport to cover it
+    pub fn bind(&self) {
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, self.id);
+            gl::Viewport(0, 0, self.spec.width as i32, self.spec.height as i32);
+        }
+    }
+
+    // Reallocates the attachments at the new size, their contents are lost.
+    // Does nothing if the size is the same.
+    pub fn resize(&mut self, width: u32, height: u32) -> Result<(), FramebufferError> {
+        if (width, height) == (self.spec.width, self.spec.height) {
+            return Ok(());
+        }
+        let spec = FramebufferBuilder { width, height, ..self.spec.clone() };
+        match self.allocate(&spec) {
+            Ok(()) => {
+                self.spec = spec;
+                Ok(())
+            },
+            Err(err) => {
+                // Limits are checked before anything is replaced, an incomplete
+                // framebuffer gets its old attachments back
+                if let FramebufferError::Incomplete { .. } = err {
+                    let old = self.spec.clone();
+                    let _ = self.allocate(&old);
+                }
+                Err(err)
+            },
+        }
+    }
+
+    // Copies every color attachment into the one with the same index in
+    // target, and depth/stencil if both have the same depth format. Resolves
+    // multisampled attachments, which needs target to be the same size.
+    // Leaves target bound for drawing and reading.
+    pub fn resolve(&self, target: &Framebuffer) {
+        let (width, height) = (target.spec.width as i32, target.spec.height as i32);
+        let scaled = (width, height) != (self.spec.width as i32, self.spec.height as i32);
+        unsafe {
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, self.id);
+            gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, target.id);
+            for index in 0..self.colors.len().min(target.colors.len()) {
+                let attachment = gl::COLOR_ATTACHMENT0 + index as GLenum;
+                gl::ReadBuffer(attachment);
+                gl::DrawBuffers(1, &attachment);
+                self.blit(width, height, gl::COLOR_BUFFER_BIT, if scaled { gl::LINEAR } else { gl::NEAREST });
+            }
+            match (self.spec.depth, target.spec.depth) {
+                (Some((format, _)), Some((target_format, _))) if format == target_format => {
+                    let mask = if format.has_stencil() { gl::DEPTH_BUFFER_BIT | gl::STENCIL_BUFFER_BIT } else { gl::DEPTH_BUFFER_BIT };
+                    self.blit(width, height, mask, gl::NEAREST);
+                },
+                _ => {},
+            }
+            self.set_draw_buffers();
+            target.set_draw_buffers();
+            gl::BindFramebuffer(gl::FRAMEBUFFER, target.id);
+        }
+    }
+
+    // Copies the first color attachment to the window's back buffer, scaled
+    // to width x height. Multisampled framebuffers can only be blitted at
+    // their own size, so scaling one resolves it into a temporary framebuffer
+    // first. Leaves the window's framebuffer bound for drawing and reading.
+    pub fn blit_to_default(&self, width: u32, height: u32) -> Result<(), FramebufferError> {
+        let scaled = (width, height) != (self.spec.width, self.spec.height);
+        if scaled && self.is_multisampled() {
+            if let Some(&format) = self.spec.colors.first() {
+                let resolved = FramebufferBuilder::new(self.spec.width, self.spec.height).color(format).build()?;
+                self.resolve(&resolved);
+                return resolved.blit_to_default(width, height);
+            }
+        }
+        unsafe {
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, self.id);
+            gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, 0);
+            gl::ReadBuffer(gl::COLOR_ATTACHMENT0);
+            self.blit(width as i32, height as i32, gl::COLOR_BUFFER_BIT, if scaled { gl::LINEAR } else { gl::NEAREST });
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+        }
+        Ok(())
+    }
+
+    unsafe fn blit(&self, width: i32, height: i32, mask: gl::types::GLbitfield, filter: GLenum) {
+        gl::BlitFramebuffer(
+            0, 0, self.spec.width as i32, self.spec.height as i32,
+            0, 0, width, height,
+            mask, filter,
+        );
+    }
+
+    // Binds the framebuffer to draw to every color attachment and read from
+    // the first, or neither for depth only framebuffers
+    unsafe fn set_draw_buffers(&self) {
+        gl::BindFramebuffer(gl::FRAMEBUFFER, self.id);
+        if self.colors.is_empty() {
+            gl::DrawBuffers(1, &gl::NONE);
+            gl::ReadBuffer(gl::NONE);
+        } else {
+            let buffers: Vec<GLenum> = (0..self.colors.len()).map(|index| gl::COLOR_ATTACHMENT0 + index as GLenum).collect();
+            gl::DrawBuffers(buffers.len() as i32, buffers.as_ptr());
+            gl::ReadBuffer(gl::COLOR_ATTACHMENT0);
+        }
+    }
+
+    // Creates the attachments for spec, replacing any old ones. Nothing is
+    // replaced when spec is over the driver's limits.
+    fn allocate(&mut self, spec: &FramebufferBuilder) -> Result<(), FramebufferError> {
+        check_limits(spec)?;
+        self.delete_attachments();
+
+        let (mut draw_framebuffer, mut read_framebuffer) = (0, 0);
+        let status = unsafe {
+            gl::GetIntegerv(gl::DRAW_FRAMEBUFFER_BINDING, &mut draw_framebuffer);
+            gl::GetIntegerv(gl::READ_FRAMEBUFFER_BINDING, &mut read_framebuffer);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, self.id);
+
+            for (index, &format) in spec.colors.iter().enumerate() {
+                let texture = create_texture(format.to_gl(), spec);
+                gl::FramebufferTexture2D(gl::FRAMEBUFFER, gl::COLOR_ATTACHMENT0 + index as GLenum, texture_target(spec), texture, 0);
+                self.colors.push(texture);
+            }
+            if let Some((format, storage)) = spec.depth {
+                self.depth = match storage {
+                    DepthStorage::Renderbuffer => {
+                        let renderbuffer = create_renderbuffer(format.to_gl().0, spec);
+                        gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, format.attachment(), gl::RENDERBUFFER, renderbuffer);
+                        renderbuffer
+                    },
+                    DepthStorage::Texture => {
+                        let texture = create_texture(format.to_gl(), spec);
+                        gl::FramebufferTexture2D(gl::FRAMEBUFFER, format.attachment(), texture_target(spec), texture, 0);
+                        texture
+                    },
+                };
+            }
+            self.set_draw_buffers();
+
+            let status = gl::CheckFramebufferStatus(gl::FRAMEBUFFER);
+            gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, draw_framebuffer as GLuint);
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, read_framebuffer as GLuint);
+            status
+        };
+        self.apply_label();
+
+        if status != gl::FRAMEBUFFER_COMPLETE {
+            return Err(FramebufferError::Incomplete { status, attachment: self.find_incomplete(spec) });
+        }
+        Ok(())
+    }
+
+    // Attaches each attachment alone to a scratch framebuffer to find one
+    // the driver rejects by itself
+    fn find_incomplete(&self, spec: &FramebufferBuilder) -> Option<String> {
+        let mut scratch = 0;
+        let mut found = None;
+        unsafe {
+            let (mut draw_framebuffer, mut read_framebuffer) = (0, 0);
+            gl::GetIntegerv(gl::DRAW_FRAMEBUFFER_BINDING, &mut draw_framebuffer);
+            gl::GetIntegerv(gl::READ_FRAMEBUFFER_BINDING, &mut read_framebuffer);
+            let target = texture_target(spec);
+
+            for (index, (&texture, format)) in self.colors.iter().zip(spec.colors.iter()).enumerate() {
+                gl::GenFramebuffers(1, &mut scratch);
+                gl::BindFramebuffer(gl::FRAMEBUFFER, scratch);
+                gl::FramebufferTexture2D(gl::FRAMEBUFFER, gl::COLOR_ATTACHMENT0, target, texture, 0);
+                let status = gl::CheckFramebufferStatus(gl::FRAMEBUFFER);
+                gl::DeleteFramebuffers(1, &scratch);
+                if status != gl::FRAMEBUFFER_COMPLETE {
+                    found = Some(format!("color attachment {} ({:?})", index, format));
+                    break;
+                }
+            }
+            if let (None, Some((format, storage))) = (&found, spec.depth) {
+                gl::GenFramebuffers(1, &mut scratch);
+                gl::BindFramebuffer(gl::FRAMEBUFFER, scratch);
+                gl::DrawBuffers(1, &gl::NONE);
+                gl::ReadBuffer(gl::NONE);
+                match storage {
+                    DepthStorage::Renderbuffer =>
+                        gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, format.attachment(), gl::RENDERBUFFER, self.depth),
+                    DepthStorage::Texture =>
+                        gl::FramebufferTexture2D(gl::FRAMEBUFFER, format.attachment(), target, self.depth, 0),
+                }
+                let status = gl::CheckFramebufferStatus(gl::FRAMEBUFFER);
+                gl::DeleteFramebuffers(1, &scratch);
+                if status != gl::FRAMEBUFFER_COMPLETE {
+                    found = Some(format!("depth attachment ({:?} {:?})", format, storage));
+                }
+            }
+
+            gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, draw_framebuffer as GLuint);
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, read_framebuffer as GLuint);
+        }
+        found
+    }
+
+    fn apply_label(&self) {
+        let name = match self.label {
+            Some(ref name) => name,
+            None => return,
+        };
+        debug::label(debug::Object::Framebuffer, self.id, name);
+        for (index, &texture) in self.colors.iter().enumerate() {
+            debug::label(debug::Object::Texture, texture, &format!("{} color{}", name, index));
+        }
+        match self.spec.depth {
+            Some((_, DepthStorage::Renderbuffer)) => debug::label(debug::Object::Renderbuffer, self.depth, &format!("{} depth", name)),
+            Some((_, DepthStorage::Texture)) => debug::label(debug::Object::Texture, self.depth, &format!("{} depth", name)),
+            None => {},
+        }
+    }
+
+    fn delete_attachments(&mut self) {
+        unsafe {
+            if !self.colors.is_empty() {
+                gl::DeleteTextures(self.colors.len() as i32, self.colors.as_ptr());
+            }
+            if self.depth != 0 {
+                match self.spec.depth {
+                    Some((_, DepthStorage::Renderbuffer)) => gl::DeleteRenderbuffers(1, &self.depth),
+                    _ => gl::DeleteTextures(1, &self.depth),
+                }
+            }
+        }
+        self.colors.clear();
+        self.depth = 0;
+    }
+}
+
+impl Drop for Framebuffer {
+    fn drop(&mut self) {
+        self.delete_attachments();
+        unsafe {
+            gl::DeleteFramebuffers(1, &self.id);
+        }
+    }
+}
+
+fn check_limits(spec: &FramebufferBuilder) -> Result<(), FramebufferError> {
+    let (mut max_texture, mut max_renderbuffer, mut max_samples, mut max_colors) = (0, 0, 0, 0);
+    unsafe {
+        gl::GetIntegerv(gl::MAX_TEXTURE_SIZE, &mut max_texture);
+        gl::GetIntegerv(gl::MAX_RENDERBUFFER_SIZE, &mut max_renderbuffer);
+        gl::GetIntegerv(gl::MAX_SAMPLES, &mut max_samples);
+        gl::GetIntegerv(gl::MAX_COLOR_ATTACHMENTS, &mut max_colors);
+    }
+    // GLES 3.0 only multisamples renderbuffers, and color attachments are textures
+    if cfg!(feature = "gles30") {
+        max_samples = 1;
+    }
+    let max = max_texture.min(max_renderbuffer) as u32;
+    if spec.width == 0 || spec.height == 0 || spec.width.max(spec.height) > max {
+        return Err(FramebufferError::TooBig { width: spec.width, height: spec.height, max });
+    }
+    if spec.samples > 1 && spec.samples > max_samples as u32 {
+        return Err(FramebufferError::TooManySamples { samples: spec.samples, max: max_samples as u32 });
+    }
+    if spec.colors.len() > max_colors as usize {
+        return Err(FramebufferError::TooManyColors { colors: spec.colors.len(), max: max_colors as usize });
+    }
+    Ok(())
+}
+
+fn texture_target(spec: &FramebufferBuilder) -> GLenum {
+    #[cfg(not(feature = "gles30"))]
+    {
+        if spec.samples > 1 {
+            return gl::TEXTURE_2D_MULTISAMPLE;
+        }
+    }
+    gl::TEXTURE_2D
+}
+
+unsafe fn create_texture((internal_format, format, data_type): (GLenum, GLenum, GLenum), spec: &FramebufferBuilder) -> GLuint {
+    let mut texture = 0;
+    gl::GenTextures(1, &mut texture);
+    let target = texture_target(spec);
+    gl::BindTexture(target, texture);
+    if spec.samples > 1 {
+        // Fixed sample locations so they can be mixed with renderbuffers.
+        // check_limits keeps GLES 3.0 from getting here.
+        #[cfg(not(feature = "gles30"))]
+        gl::TexImage2DMultisample(target, spec.samples as i32, internal_format, spec.width as i32, spec.height as i32, gl::TRUE);
+    } else {
+        gl::TexImage2D(target, 0, internal_format as GLint, spec.width as i32, spec.height as i32, 0, format, data_type, std::ptr::null());
+        // Depth and float textures aren't always filterable
+        let filter = if format == gl::RGBA { gl::LINEAR } else { gl::NEAREST };
+        gl::TexParameteri(target, gl::TEXTURE_MIN_FILTER, filter as GLint);
+        gl::TexParameteri(target, gl::TEXTURE_MAG_FILTER, filter as GLint);
+        gl::TexParameteri(target, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_EDGE as GLint);
+        gl::TexParameteri(target, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_EDGE as GLint);
+    }
+    gl::BindTexture(target, 0);
+    texture
+}
+
+unsafe fn create_renderbuffer(internal_format: GLenum, spec: &FramebufferBuilder) -> GLuint {
+    let mut renderbuffer = 0;
+    gl::GenRenderbuffers(1, &mut renderbuffer);
+    gl::BindRenderbuffer(gl::RENDERBUFFER, renderbuffer);
+    if spec.samples > 1 {
+        gl::RenderbufferStorageMultisample(gl::RENDERBUFFER, spec.samples as i32, internal_format, spec.width as i32, spec.height as i32);
+    } else {
+        gl::RenderbufferStorage(gl::RENDERBUFFER, internal_format, spec.width as i32, spec.height as i32);
+    }
+    gl::BindRenderbuffer(gl::RENDERBUFFER, 0);
+    renderbuffer
+}
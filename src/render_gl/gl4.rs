@@ -0,0 +1,71 @@
+// Enums past the 3.3 core, defined here so they exist whatever version the
+// bindings target. The entry points come from the generated bindings through
+// the ARB_* features on gl. Only use them after checking context_version()
+// or has_extension().
+#![allow(non_snake_case)]
+
+use gl;
+use std;
+
+use gl::types::{GLbitfield, GLenum, GLuint};
+
+// 4.0 / ARB_tessellation_shader
+pub const TESS_CONTROL_SHADER: GLenum = 0x8E88;
+pub const TESS_EVALUATION_SHADER: GLenum = 0x8E87;
+pub const PATCHES: GLenum = 0x000E;
+pub const PATCH_VERTICES: GLenum = 0x8E72;
+pub const MAX_PATCH_VERTICES: GLenum = 0x8E7D;
+
+// 4.3 / ARB_compute_shader
+pub const COMPUTE_SHADER: GLenum = 0x91B9;
+pub const COMPUTE_WORK_GROUP_SIZE: GLenum = 0x8267;
+pub const MAX_COMPUTE_WORK_GROUP_COUNT: GLenum = 0x91BE;
+
+// glMemoryBarrier bits, 4.2
+pub const VERTEX_ATTRIB_ARRAY_BARRIER_BIT: GLbitfield = 0x0000_0001;
+pub const ELEMENT_ARRAY_BARRIER_BIT: GLbitfield = 0x0000_0002;
+pub const UNIFORM_BARRIER_BIT: GLbitfield = 0x0000_0004;
+pub const TEXTURE_FETCH_BARRIER_BIT: GLbitfield = 0x0000_0008;
+pub const SHADER_IMAGE_ACCESS_BARRIER_BIT: GLbitfield = 0x0000_0020;
+pub const BUFFER_UPDATE_BARRIER_BIT: GLbitfield = 0x0000_0200;
+pub const FRAMEBUFFER_BARRIER_BIT: GLbitfield = 0x0000_0400;
+pub const SHADER_STORAGE_BARRIER_BIT: GLbitfield = 0x0000_2000;
+pub const ALL_BARRIER_BITS: GLbitfield = 0xFFFF_FFFF;
+
+// 4.4 / ARB_buffer_storage, storage flags and glMapBufferRange access bits
+pub const MAP_PERSISTENT_BIT: GLbitfield = 0x0040;
+pub const MAP_COHERENT_BIT: GLbitfield = 0x0080;
+pub const DYNAMIC_STORAGE_BIT: GLbitfield = 0x0100;
+pub const CLIENT_STORAGE_BIT: GLbitfield = 0x0200;
+
+// (major, minor) of the current context
+pub fn context_version() -> (u32, u32) {
+    let (mut major, mut minor) = (0, 0);
+    unsafe {
+        gl::GetIntegerv(gl::MAJOR_VERSION, &mut major);
+        gl::GetIntegerv(gl::MINOR_VERSION, &mut minor);
+    }
+    (major as u32, minor as u32)
+}
+
+pub fn supports(version: (u32, u32)) -> bool {
+    context_version() >= version
+}
+
+pub fn has_extension(name: &str) -> bool {
+    let mut count = 0;
+    unsafe {
+        gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut count);
+    }
+    (0..count.max(0) as GLuint).any(|index| {
+        let extension = unsafe { gl::GetStringi(gl::EXTENSIONS, index) };
+        !extension.is_null() && unsafe { std::ffi::CStr::from_ptr(extension as *const _) }.to_bytes() == name.as_bytes()
+    })
+}
+
+// Immutable buffer storage, which persistent mappings need. Drivers can
+// expose it through the extension below 4.4.
+#[cfg(not(feature = "gles30"))]
+pub fn has_buffer_storage() -> bool {
+    gl::BufferStorage::is_loaded() && (supports((4, 4)) || has_extension("GL_ARB_buffer_storage"))
+}
@@ -0,0 +1,357 @@
+use gl;
+use std::fmt::Write;
+
+use super::error::ShaderError;
+
+// A file that went into a preprocessed source. Its index in Preprocessed::files
+// is the source string number the #line directives use for it.
+#[derive(Clone, Debug)]
+pub struct SourceFile {
+    pub name: String,
+    pub source: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct Preprocessed {
+    pub source: String,
+    pub files: Vec<SourceFile>,
+}
+
+impl Preprocessed {
+    // A source that didn't go through the preprocessor
+    pub fn unprocessed(name: &str, source: &str) -> Self {
+        Preprocessed {
+            source: source.to_string(),
+            files: vec![SourceFile { name: name.to_string(), source: source.to_string() }],
+        }
+    }
+}
+
+// Fetches an include by its resolved name
+pub type Loader<'a> = Box<dyn Fn(&str) -> Result<String, String> + 'a>;
+
+// Resolves #include "file" relative to the including file, with the loader
+// fetching the resolved name, and injects #defines right after #version.
+// Includes are expanded even inside #if blocks, files with #pragma once are
+// only expanded the first time. For GLES the desktop #version is replaced
+// with 300 es and default precisions.
+pub struct Preprocessor<'a> {
+    loader: Loader<'a>,
+    defines: Vec<(String, String)>,
+    gles: bool,
+}
+
+impl<'a> Preprocessor<'a> {
+    pub fn new<F>(loader: F) -> Self
+        where F: Fn(&str) -> Result<String, String> + 'a
+    {
+        Preprocessor { loader: Box::new(loader), defines: Vec::new(), gles: gl::TARGET_GLES }
+    }
+
+    // Defaults to what the bindings were generated for
+    pub fn gles(&mut self, gles: bool) -> &mut Self {
+        self.gles = gles;
+        self
+    }
+
+    // Replaces an earlier define with the same name
+    pub fn define(&mut self, name: &str, value: &str) -> &mut Self {
+        self.defines.retain(|(n, _)| n != name);
+        self.defines.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn defines(&self) -> &[(String, String)] {
+        &self.defines
+    }
+
+    pub fn run(&self, name: &str, source: &str) -> Result<Preprocessed, ShaderError> {
+        let mut state = State {
+            output: String::new(),
+            files: Vec::new(),
+            stack: Vec::new(),
+            seen_version: false,
+        };
+        // Without #version the defines go first
+        if !source.lines().any(|line| line.trim_start().starts_with("#version")) {
+            state.seen_version = true;
+            self.write_defines(&mut state.output);
+            writeln!(state.output, "#line 1 0").unwrap();
+        }
+        self.expand(&mut state, name, source.to_string())?;
+        Ok(Preprocessed { source: state.output, files: state.files })
+    }
+
+    fn write_version(&self, output: &mut String, line: &str) {
+        if !self.gles {
+            writeln!(output, "{}", line).unwrap();
+            return;
+        }
+        if line.split_whitespace().last() == Some("es") {
+            writeln!(output, "{}", line).unwrap();
+        } else {
+            writeln!(output, "#version 300 es").unwrap();
+        }
+        // Fragment shaders have no default float precision
+        writeln!(output, "precision highp float;").unwrap();
+        writeln!(output, "precision highp int;").unwrap();
+    }
+
+    fn write_defines(&self, output: &mut String) {
+        for (define, value) in self.defines.iter() {
+            writeln!(output, "#define {} {}", define, value).unwrap();
+        }
+    }
+
+    fn expand(&self, state: &mut State, name: &str, source: String) -> Result<(), ShaderError> {
+        let index = state.files.len();
+        state.files.push(SourceFile { name: name.to_string(), source: source.clone() });
+        state.stack.push(name.to_string());
+
+        for (i, line) in source.lines().enumerate() {
+            let line_number = i + 1;
+            let directive = line.trim_start();
+
+            if directive.starts_with("#version") && !state.seen_version {
+                state.seen_version = true;
+                self.write_version(&mut state.output, line);
+                self.write_defines(&mut state.output);
+                writeln!(state.output, "#line {} {}", line_number + 1, index).unwrap();
+            } else if let Some(rest) = directive_argument(directive, "include") {
+                let path = parse_include_path(rest).ok_or_else(|| ShaderError::Preprocess {
+                    file: name.to_string(),
+                    line: line_number,
+                    message: format!("Expected #include \"file\", got {}", directive),
+                })?;
+                let resolved = resolve(name, path);
+
+                if state.stack.contains(&resolved) {
+                    let mut chain = state.stack.clone();
+                    chain.push(resolved);
+                    return Err(ShaderError::Preprocess {
+                        file: name.to_string(),
+                        line: line_number,
+                        message: format!("Include cycle: {}", chain.join(" -> ")),
+                    });
+                }
+                if state.files.iter().any(|file| file.name == resolved && has_pragma_once(&file.source)) {
+                    // Keep the line count so the next line isn't off by one
+                    writeln!(state.output).unwrap();
+                    continue;
+                }
+
+                let included = (self.loader)(&resolved).map_err(|err| ShaderError::Preprocess {
+                    file: name.to_string(),
+                    line: line_number,
+                    message: format!("Couldn't include \"{}\": {}", resolved, err),
+                })?;
+                writeln!(state.output, "#line 1 {}", state.files.len()).unwrap();
+                self.expand(state, &resolved, included)?;
+                writeln!(state.output, "#line {} {}", line_number + 1, index).unwrap();
+            } else if directive_argument(directive, "pragma").map(|rest| rest.trim()) == Some("once") {
+                writeln!(state.output).unwrap();
+            } else {
+                writeln!(state.output, "{}", line).unwrap();
+            }
+        }
+
+        state.stack.pop();
+        Ok(())
+    }
+}
+
+struct State {
+    output: String,
+    files: Vec<SourceFile>,
+    // Names of the files being expanded, to catch include cycles
+    stack: Vec<String>,
+    seen_version: bool,
+}
+
+// "#  include ..." -> Some("...")
+fn directive_argument<'s>(line: &'s str, directive: &str) -> Option<&'s str> {
+    let rest = line.strip_prefix('#')?.trim_start();
+    let rest = rest.strip_prefix(directive)?;
+    if rest.is_empty() || rest.starts_with(char::is_whitespace) || rest.starts_with('"') || rest.starts_with('<') {
+        Some(rest.trim_start())
+    } else {
+        None
+    }
+}
+
+fn parse_include_path(rest: &str) -> Option<&str> {
+    let (open, close) = match rest.chars().next()? {
+        '"' => ('"', '"'),
+        '<' => ('<', '>'),
+        _ => return None,
+    };
+    let rest = &rest[open.len_utf8()..];
+    let end = rest.find(close)?;
+    let after = rest[end + 1..].trim();
+    if end == 0 || !(after.is_empty() || after.starts_with("//")) {
+        return None;
+    }
+    Some(&rest[..end])
+}
+
+fn has_pragma_once(source: &str) -> bool {
+    source.lines().any(|line| directive_argument(line.trim_start(), "pragma").map(|rest| rest.trim()) == Some("once"))
+}
+
+// Paths are relative to the including file, "/" separated, with . and .. resolved.
+// A leading "/" makes the path relative to the loader's root instead.
+fn resolve(including: &str, path: &str) -> String {
+    let mut parts: Vec<&str> = if path.starts_with('/') {
+        Vec::new()
+    } else {
+        let mut dir: Vec<&str> = including.split('/').collect();
+        dir.pop();
+        dir
+    };
+    for part in path.split('/') {
+        match part {
+            "" | "." => {},
+            ".." => { parts.pop(); },
+            part => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn no_includes(name: &str) -> Result<String, String> {
+        Err(format!("no {}", name))
+    }
+
+    // Runs main with includes served from files
+    fn run(files: &[(&str, &str)], name: &str, main: &str) -> Result<Preprocessed, ShaderError> {
+        let files: HashMap<String, String> = files.iter().map(|(name, source)| (name.to_string(), source.to_string())).collect();
+        let mut preprocessor = Preprocessor::new(move |name: &str| files.get(name).cloned().ok_or_else(|| String::from("not found")));
+        preprocessor.gles(false);
+        preprocessor.run(name, main)
+    }
+
+    fn names(preprocessed: &Preprocessed) -> Vec<&str> {
+        preprocessed.files.iter().map(|file| file.name.as_str()).collect()
+    }
+
+    fn preprocess_error(result: Result<Preprocessed, ShaderError>) -> (String, usize, String) {
+        match result {
+            Err(ShaderError::Preprocess { file, line, message }) => (file, line, message),
+            other => panic!("expected a preprocess error, got {:?}", other.map(|out| out.source)),
+        }
+    }
+
+    #[test]
+    fn resolves_relative_parent_and_absolute_includes() {
+        let files = [
+            ("shaders/common/a.glsl", "a\n#include \"../lib/b.glsl\"\n"),
+            ("shaders/lib/b.glsl", "b\n#include \"/root.glsl\"\n"),
+            ("root.glsl", "root\n"),
+            ("shaders/c.glsl", "c\n"),
+        ];
+        let main = "#include \"common/a.glsl\"\n#include <./c.glsl>\n";
+        let out = run(&files, "shaders/main.frag", main).unwrap();
+        assert_eq!(names(&out), vec!["shaders/main.frag", "shaders/common/a.glsl", "shaders/lib/b.glsl", "root.glsl", "shaders/c.glsl"]);
+        let code: Vec<&str> = out.source.lines().filter(|line| !line.starts_with('#')).collect();
+        assert_eq!(code, vec!["a", "b", "root", "c"]);
+    }
+
+    #[test]
+    fn reports_missing_and_malformed_includes() {
+        let (file, line, message) = preprocess_error(run(&[], "dir/main.frag", "\n#include \"missing.glsl\"\n"));
+        assert_eq!((file.as_str(), line), ("dir/main.frag", 2));
+        assert_eq!(message, "Couldn't include \"dir/missing.glsl\": not found");
+
+        let (_, line, message) = preprocess_error(run(&[], "main.frag", "#include missing.glsl\n"));
+        assert_eq!(line, 1);
+        assert_eq!(message, "Expected #include \"file\", got #include missing.glsl");
+    }
+
+    #[test]
+    fn rejects_include_cycles() {
+        let files = [
+            ("a.glsl", "#include \"b.glsl\"\n"),
+            ("b.glsl", "// b\n#include \"a.glsl\"\n"),
+        ];
+        let (file, line, message) = preprocess_error(run(&files, "main.frag", "#include \"a.glsl\"\n"));
+        assert_eq!((file.as_str(), line), ("b.glsl", 2));
+        assert_eq!(message, "Include cycle: main.frag -> a.glsl -> b.glsl -> a.glsl");
+
+        let (_, _, message) = preprocess_error(run(&[], "self.glsl", "#include \"self.glsl\"\n"));
+        assert_eq!(message, "Include cycle: self.glsl -> self.glsl");
+    }
+
+    #[test]
+    fn expands_pragma_once_files_once() {
+        let files = [
+            ("once.glsl", "#pragma once\nonce\n"),
+            ("twice.glsl", "twice\n"),
+        ];
+        let main = "#include \"once.glsl\"\n#include \"twice.glsl\"\n#include \"once.glsl\"\n#include \"twice.glsl\"\nend\n";
+        let out = run(&files, "main.frag", main).unwrap();
+        assert_eq!(names(&out), vec!["main.frag", "once.glsl", "twice.glsl", "twice.glsl"]);
+        assert_eq!(out.source.matches("once\n").count(), 1);
+        assert_eq!(out.source.matches("twice\n").count(), 2);
+        // The skipped include still takes up its line
+        assert!(out.source.ends_with("#line 3 0\n\n#line 1 3\ntwice\n#line 5 0\nend\n"));
+    }
+
+    #[test]
+    fn inserts_defines_after_the_version() {
+        let mut preprocessor = Preprocessor::new(no_includes);
+        preprocessor.gles(false).define("A", "1").define("B", "2").define("A", "3");
+        assert_eq!(preprocessor.defines(), &[(String::from("B"), String::from("2")), (String::from("A"), String::from("3"))]);
+
+        let out = preprocessor.run("main.frag", "// header\n#version 330 core\nvoid main() {}\n").unwrap();
+        assert_eq!(out.source, "// header\n#version 330 core\n#define B 2\n#define A 3\n#line 3 0\nvoid main() {}\n");
+
+        // Without #version the defines go first
+        let out = preprocessor.run("main.frag", "void main() {}\n").unwrap();
+        assert_eq!(out.source, "#define B 2\n#define A 3\n#line 1 0\nvoid main() {}\n");
+    }
+
+    #[test]
+    fn numbers_lines_after_includes() {
+        let files = [
+            ("inner.glsl", "inner 1\ninner 2\n"),
+            ("outer.glsl", "outer 1\n#include \"inner.glsl\"\nouter 3\n"),
+        ];
+        let main = "#version 330 core\nmain 2\n#include \"outer.glsl\"\nmain 4\n";
+        let out = run(&files, "main.frag", main).unwrap();
+        assert_eq!(out.source, "\
+#version 330 core
+#line 2 0
+main 2
+#line 1 1
+outer 1
+#line 1 2
+inner 1
+inner 2
+#line 3 1
+outer 3
+#line 4 0
+main 4
+");
+        assert_eq!(out.files[1].source, files[1].1);
+    }
+
+    #[test]
+    fn rewrites_the_version_for_gles() {
+        let mut preprocessor = Preprocessor::new(no_includes);
+        preprocessor.gles(true).define("A", "1");
+        let out = preprocessor.run("main.frag", "#version 330 core\nout vec4 color;\n").unwrap();
+        assert_eq!(out.source, "#version 300 es\nprecision highp float;\nprecision highp int;\n#define A 1\n#line 2 0\nout vec4 color;\n");
+
+        let out = preprocessor.run("main.frag", "#version 310 es\n").unwrap();
+        assert!(out.source.starts_with("#version 310 es\nprecision highp float;\n"));
+
+        preprocessor.gles(false);
+        let out = preprocessor.run("main.frag", "#version 330 core\n").unwrap();
+        assert!(out.source.starts_with("#version 330 core\n#define A 1\n"));
+    }
+}
@@ -0,0 +1,134 @@
+use gl;
+use std;
+
+use cgmath::{Vector2, Vector3, Vector4, Matrix3, Matrix4};
+
+// A type whose values (or arrays of them) can be uploaded to a uniform
+pub trait UniformElement: Sized {
+    // Whether a uniform reflected with this gl type can be set from Self
+    fn accepts(gl_type: gl::types::GLenum) -> bool;
+    unsafe fn upload(values: &[Self], location: gl::types::GLint);
+}
+
+// Anything that can be passed to Program::set_uniform: single values, slices and vecs
+pub trait Uniform {
+    type Element: UniformElement;
+    fn elements(&self) -> &[Self::Element];
+}
+
+impl<T: UniformElement> Uniform for &[T] {
+    type Element = T;
+    fn elements(&self) -> &[T] { self }
+}
+
+impl<T: UniformElement> Uniform for Vec<T> {
+    type Element = T;
+    fn elements(&self) -> &[T] { self }
+}
+
+macro_rules! single_value_uniform {
+    ($($t:ty),*) => {
+        $(
+            impl Uniform for $t {
+                type Element = $t;
+                fn elements(&self) -> &[$t] { std::slice::from_ref(self) }
+            }
+        )*
+    }
+}
+
+single_value_uniform!(f32, i32, bool, Vector2<f32>, Vector3<f32>, Vector4<f32>, Matrix3<f32>, Matrix4<f32>);
+
+fn is_sampler(gl_type: gl::types::GLenum) -> bool {
+    matches!(gl_type,
+        gl::SAMPLER_2D | gl::SAMPLER_3D | gl::SAMPLER_CUBE |
+        gl::SAMPLER_2D_SHADOW | gl::SAMPLER_CUBE_SHADOW |
+        gl::SAMPLER_2D_ARRAY | gl::SAMPLER_2D_ARRAY_SHADOW |
+        gl::INT_SAMPLER_2D | gl::INT_SAMPLER_3D | gl::INT_SAMPLER_CUBE | gl::INT_SAMPLER_2D_ARRAY |
+        gl::UNSIGNED_INT_SAMPLER_2D | gl::UNSIGNED_INT_SAMPLER_3D | gl::UNSIGNED_INT_SAMPLER_CUBE |
+        gl::UNSIGNED_INT_SAMPLER_2D_ARRAY) || is_desktop_sampler(gl_type)
+}
+
+#[cfg(not(feature = "gles30"))]
+fn is_desktop_sampler(gl_type: gl::types::GLenum) -> bool {
+    matches!(gl_type,
+        gl::SAMPLER_1D | gl::SAMPLER_1D_SHADOW | gl::SAMPLER_1D_ARRAY | gl::SAMPLER_1D_ARRAY_SHADOW |
+        gl::SAMPLER_2D_MULTISAMPLE | gl::SAMPLER_2D_MULTISAMPLE_ARRAY |
+        gl::SAMPLER_BUFFER | gl::SAMPLER_2D_RECT | gl::SAMPLER_2D_RECT_SHADOW)
+}
+
+#[cfg(feature = "gles30")]
+fn is_desktop_sampler(_gl_type: gl::types::GLenum) -> bool {
+    false
+}
+
+impl UniformElement for f32 {
+    fn accepts(gl_type: gl::types::GLenum) -> bool { gl_type == gl::FLOAT }
+    unsafe fn upload(values: &[f32], location: gl::types::GLint) {
+        gl::Uniform1fv(location, values.len() as gl::types::GLsizei, values.as_ptr());
+    }
+}
+
+// Samplers are set with their texture unit as an int
+impl UniformElement for i32 {
+    fn accepts(gl_type: gl::types::GLenum) -> bool {
+        gl_type == gl::INT || gl_type == gl::BOOL || is_sampler(gl_type)
+    }
+    unsafe fn upload(values: &[i32], location: gl::types::GLint) {
+        gl::Uniform1iv(location, values.len() as gl::types::GLsizei, values.as_ptr());
+    }
+}
+
+impl UniformElement for bool {
+    fn accepts(gl_type: gl::types::GLenum) -> bool { gl_type == gl::BOOL }
+    unsafe fn upload(values: &[bool], location: gl::types::GLint) {
+        let ints: Vec<gl::types::GLint> = values.iter().map(|&b| b as gl::types::GLint).collect();
+        gl::Uniform1iv(location, ints.len() as gl::types::GLsizei, ints.as_ptr());
+    }
+}
+
+// cgmath types are repr(C) structs of floats, so slices of them can be passed as is
+macro_rules! float_uniform {
+    ($t:ty, $gl_type:path, $func:ident) => {
+        impl UniformElement for $t {
+            fn accepts(gl_type: gl::types::GLenum) -> bool { gl_type == $gl_type }
+            unsafe fn upload(values: &[$t], location: gl::types::GLint) {
+                gl::$func(location, values.len() as gl::types::GLsizei, values.as_ptr() as *const gl::types::GLfloat);
+            }
+        }
+    }
+}
+
+macro_rules! matrix_uniform {
+    ($t:ty, $gl_type:path, $func:ident) => {
+        impl UniformElement for $t {
+            fn accepts(gl_type: gl::types::GLenum) -> bool { gl_type == $gl_type }
+            unsafe fn upload(values: &[$t], location: gl::types::GLint) {
+                // cgmath matrices are column major like gl expects
+                gl::$func(location, values.len() as gl::types::GLsizei, gl::FALSE, values.as_ptr() as *const gl::types::GLfloat);
+            }
+        }
+    }
+}
+
+float_uniform!(Vector2<f32>, gl::FLOAT_VEC2, Uniform2fv);
+float_uniform!(Vector3<f32>, gl::FLOAT_VEC3, Uniform3fv);
+float_uniform!(Vector4<f32>, gl::FLOAT_VEC4, Uniform4fv);
+matrix_uniform!(Matrix3<f32>, gl::FLOAT_MAT3, UniformMatrix3fv);
+matrix_uniform!(Matrix4<f32>, gl::FLOAT_MAT4, UniformMatrix4fv);
+
+// Readable name of a reflected uniform type, used in error messages
+pub fn type_name(gl_type: gl::types::GLenum) -> &'static str {
+    match gl_type {
+        gl::FLOAT => "float",
+        gl::FLOAT_VEC2 => "vec2",
+        gl::FLOAT_VEC3 => "vec3",
+        gl::FLOAT_VEC4 => "vec4",
+        gl::INT => "int",
+        gl::BOOL => "bool",
+        gl::FLOAT_MAT3 => "mat3",
+        gl::FLOAT_MAT4 => "mat4",
+        t if is_sampler(t) => "sampler",
+        _ => "other",
+    }
+}
@@ -0,0 +1,203 @@
+// How vertex data is laid out in a buffer. A VertexLayout lists the
+// attributes with their shader locations, formats and byte offsets, and sets
+// up the bound vertex array from them. Vertex structs get one from
+// impl_vertex!, which reads the formats and offsets off the fields.
+use gl;
+use std;
+
+use gl::types::{GLenum, GLint, GLuint};
+
+// Implements Vertex for a #[repr(C)] struct from its fields and their
+// attribute locations. Fields need AttributeFormat types, wrap integer ones
+// in Normalized to read them as 0-1 floats.
+//   impl_vertex!(ColoredVertex { position: 0, color: 4 });
+macro_rules! impl_vertex {
+    ($vertex:ident { $($field:ident: $location:expr),* $(,)* }) => {
+        impl $crate::render_gl::vertex::Vertex for $vertex {
+            fn layout() -> $crate::render_gl::vertex::VertexLayout {
+                $crate::render_gl::vertex::VertexLayout::new()
+                    $(.attribute($crate::render_gl::vertex::Attribute::of_field(
+                        $location,
+                        std::mem::offset_of!($vertex, $field),
+                        |vertex: &$vertex| &vertex.$field,
+                    )))*
+                    .with_stride(std::mem::size_of::<$vertex>())
+            }
+        }
+    };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributeType {
+    Float,
+    HalfFloat,
+    Byte,
+    UnsignedByte,
+    Short,
+    UnsignedShort,
+    Int,
+    UnsignedInt,
+}
+
+impl AttributeType {
+    pub fn to_gl(self) -> GLenum {
+        match self {
+            AttributeType::Float => gl::FLOAT,
+            AttributeType::HalfFloat => gl::HALF_FLOAT,
+            AttributeType::Byte => gl::BYTE,
+            AttributeType::UnsignedByte => gl::UNSIGNED_BYTE,
+            AttributeType::Short => gl::SHORT,
+            AttributeType::UnsignedShort => gl::UNSIGNED_SHORT,
+            AttributeType::Int => gl::INT,
+            AttributeType::UnsignedInt => gl::UNSIGNED_INT,
+        }
+    }
+
+    pub fn size(self) -> usize {
+        match self {
+            AttributeType::Byte | AttributeType::UnsignedByte => 1,
+            AttributeType::HalfFloat | AttributeType::Short | AttributeType::UnsignedShort => 2,
+            AttributeType::Float | AttributeType::Int | AttributeType::UnsignedInt => 4,
+        }
+    }
+
+    pub fn is_integer(self) -> bool {
+        !matches!(self, AttributeType::Float | AttributeType::HalfFloat)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Attribute {
+    pub location: GLuint,
+    // 1 to 4
+    pub components: GLint,
+    pub kind: AttributeType,
+    // Integer types map to 0-1 (or -1-1) floats when set, and are read as
+    // ivec/uvec in the shader when not, like joint indices
+    pub normalized: bool,
+    // Bytes from the start of the vertex
+    pub offset: usize,
+}
+
+impl Attribute {
+    pub fn new(location: GLuint, components: GLint, kind: AttributeType, normalized: bool, offset: usize) -> Self {
+        Attribute { location, components, kind, normalized, offset }
+    }
+
+    // An attribute in the format of a field, used by impl_vertex!
+    pub fn of_field<V, T: AttributeFormat>(location: GLuint, offset: usize, _field: fn(&V) -> &T) -> Self {
+        Attribute::new(location, T::COMPONENTS, T::KIND, T::NORMALIZED, offset)
+    }
+
+    pub fn size(&self) -> usize {
+        self.components as usize * self.kind.size()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VertexLayout {
+    attributes: Vec<Attribute>,
+    stride: usize,
+}
+
+impl VertexLayout {
+    pub fn new() -> Self {
+        VertexLayout { attributes: Vec::new(), stride: 0 }
+    }
+
+    // Appends an attribute right after the previous one
+    pub fn push(self, location: GLuint, components: GLint, kind: AttributeType, normalized: bool) -> Self {
+        let offset = self.attributes.iter().map(|attribute| attribute.offset + attribute.size()).max().unwrap_or(0);
+        self.attribute(Attribute::new(location, components, kind, normalized, offset))
+    }
+
+    // Adds an attribute at its own offset, the stride grows to fit it
+    pub fn attribute(mut self, attribute: Attribute) -> Self {
+        self.stride = self.stride.max(attribute.offset + attribute.size());
+        self.attributes.push(attribute);
+        self
+    }
+
+    // For vertices with padding or attributes that aren't used
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        self.stride = stride;
+        self
+    }
+
+    pub fn attributes(&self) -> &[Attribute] { &self.attributes }
+    pub fn stride(&self) -> usize { self.stride }
+
+    pub fn find(&self, location: GLuint) -> Option<&Attribute> {
+        self.attributes.iter().find(|attribute| attribute.location == location)
+    }
+
+    // Points the bound vertex array's attributes into the bound GL_ARRAY_BUFFER,
+    // starting base_offset bytes in
+    pub fn apply(&self, base_offset: usize) {
+        for attribute in &self.attributes {
+            let pointer = (base_offset + attribute.offset) as *const gl::types::GLvoid;
+            unsafe {
+                if attribute.kind.is_integer() && !attribute.normalized {
+                    gl::VertexAttribIPointer(attribute.location, attribute.components, attribute.kind.to_gl(), self.stride as GLint, pointer);
+                } else {
+                    let normalized = if attribute.normalized { gl::TRUE } else { gl::FALSE };
+                    gl::VertexAttribPointer(attribute.location, attribute.components, attribute.kind.to_gl(), normalized, self.stride as GLint, pointer);
+                }
+                gl::EnableVertexAttribArray(attribute.location);
+            }
+        }
+    }
+}
+
+// A #[repr(C)] vertex struct that knows its layout, see impl_vertex!
+pub trait Vertex: Copy {
+    fn layout() -> VertexLayout;
+}
+
+// The bytes of a slice of vertices, for uploading
+pub fn as_bytes<V: Vertex>(vertices: &[V]) -> &[u8] {
+    unsafe { std::slice::from_raw_parts(vertices.as_ptr() as *const u8, std::mem::size_of_val(vertices)) }
+}
+
+// A field type that maps to one vertex attribute
+pub trait AttributeFormat {
+    const COMPONENTS: GLint;
+    const KIND: AttributeType;
+    const NORMALIZED: bool = false;
+}
+
+// Reads an integer field as a normalized float, like a u8 color
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(transparent)]
+pub struct Normalized<T>(pub T);
+
+impl<T: AttributeFormat> AttributeFormat for Normalized<T> {
+    const COMPONENTS: GLint = T::COMPONENTS;
+    const KIND: AttributeType = T::KIND;
+    const NORMALIZED: bool = true;
+}
+
+macro_rules! attribute_format {
+    ($($t:ty => $kind:ident),*) => {
+        $(
+            impl AttributeFormat for $t {
+                const COMPONENTS: GLint = 1;
+                const KIND: AttributeType = AttributeType::$kind;
+            }
+            impl AttributeFormat for [$t; 2] {
+                const COMPONENTS: GLint = 2;
+                const KIND: AttributeType = AttributeType::$kind;
+            }
+            impl AttributeFormat for [$t; 3] {
+                const COMPONENTS: GLint = 3;
+                const KIND: AttributeType = AttributeType::$kind;
+            }
+            impl AttributeFormat for [$t; 4] {
+                const COMPONENTS: GLint = 4;
+                const KIND: AttributeType = AttributeType::$kind;
+            }
+        )*
+    }
+}
+
+attribute_format!(f32 => Float, i8 => Byte, u8 => UnsignedByte, i16 => Short, u16 => UnsignedShort, i32 => Int, u32 => UnsignedInt);
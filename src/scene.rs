@@ -0,0 +1,142 @@
+use gl;
+
+use cgmath::{Deg, Matrix4, Vector3};
+
+use camera;
+use light;
+use model;
+use render_gl::debug::DebugGroup;
+use render_gl::ShaderError;
+use shader_library::{Features, ShaderLibrary, StageSource};
+
+// The lit cube and its lamp. Doesn't care where the context comes from, so it
+// renders the same into the window or offscreen.
+pub struct Scene {
+    pub camera: camera::Camera,
+    shaders: ShaderLibrary,
+    cube: model::Model,
+    light_vao: model::Model,
+    cube_features: Features,
+    lights: light::LightSet,
+    lamp: usize,
+    light_pos: Vector3<f32>,
+}
+
+impl Scene {
+    // Needs a current context with gl loaded
+    pub fn new() -> Result<Scene, ShaderError> {
+        // Shaders are read from src/ in debug builds so they can be edited while running
+        let mut shaders = ShaderLibrary::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src"));
+        shaders.add_include("common/transforms.glsl", include_str!("common/transforms.glsl"));
+        shaders.add_include("common/lighting.glsl", include_str!("common/lighting.glsl"));
+        shaders.define("MAX_DIR_LIGHTS", &light::MAX_DIR_LIGHTS.to_string());
+        shaders.define("MAX_POINT_LIGHTS", &light::MAX_POINT_LIGHTS.to_string());
+        shaders.define("MAX_SPOT_LIGHTS", &light::MAX_SPOT_LIGHTS.to_string());
+        shaders.add("lighting", &[
+            StageSource::vertex("triangle.vert", include_str!("triangle.vert")),
+            StageSource::fragment("light.frag", include_str!("light.frag"))
+        ])?;
+        shaders.add("lamp", &[
+            StageSource::vertex("lamp.vert", include_str!("lamp.vert")),
+            StageSource::fragment("lamp.frag", include_str!("lamp.frag"))
+        ])?;
+
+        let cube = model::Model::cube().with_label("cube");
+        let light_vao = model::Model::light().with_label("lamp");
+
+        // Compile the cube's variant up front so a broken permutation fails at startup
+        let cube_features = cube.get_material().features();
+        shaders.variant("lighting", &cube_features)?;
+
+        // Light location
+        let light_pos = Vector3{x: 0.4f32, y: 0.2, z: 3.0};
+
+        // Camera variables
+        let camera_pos = Vector3{x: 0.0f32, y: 0.0, z: 3.0};
+        let world_up = Vector3{x: 0.0f32, y: 1.0, z: 0.0};
+
+        let yaw = 270.0f32; // For some reason have to put in a lot of yaw at beginning
+        let pitch = 0f32;
+
+        let camera = camera::Camera::new(
+            camera_pos, world_up, yaw, pitch);
+
+        let mut lights = light::LightSet::new();
+        let lamp = lights.push(light::Light::Point {
+            position: light_pos,
+            attenuation: light::Attenuation::none(),
+            colors: light::LightColors::new(
+                Vector3::new(0.2, 0.2, 0.2),
+                Vector3::new(0.5, 0.5, 0.5), // darken the light a bit to fit the scene
+                Vector3::new(1.0, 1.0, 1.0)
+            ),
+        });
+
+        Ok(Scene { camera, shaders, cube, light_vao, cube_features, lights, lamp, light_pos })
+    }
+
+    pub fn shaders(&self) -> &ShaderLibrary {
+        &self.shaders
+    }
+
+    // Draws a frame into the bound framebuffer. time is in seconds and drives
+    // the light's color, so equal times give equal frames.
+    pub fn render(&mut self, time: f32, width: u32, height: u32) {
+        self.shaders.poll();
+        // Reloaded variants start with all uniforms reset, so the material is set every frame
+        let lightning_shader = self.shaders.variant("lighting", &self.cube_features).unwrap();
+        let lamp_shader = &self.shaders["lamp"];
+        self.cube.get_material().apply(&lightning_shader);
+
+        unsafe {
+            gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
+        }
+
+        // Move light
+        //light_pos.x = (time * 0.4).sin() * 5.0;
+        let light_color = Vector3::new(
+            (time * 2.0).sin(),
+            (time * 0.7).sin(),
+            (time * 1.3).sin()
+        );
+
+        let diffuse_color = light_color * 0.5;
+        let ambient_color = diffuse_color * 0.2;
+
+        if let Some(lamp) = self.lights.get_mut(self.lamp) {
+            lamp.set_position(self.light_pos);
+            lamp.colors_mut().ambient = ambient_color;
+            lamp.colors_mut().diffuse = diffuse_color;
+        }
+        self.lights.apply(&lightning_shader);
+
+        // Projection matrix
+        let projection : Matrix4<f32> = cgmath::PerspectiveFov{
+            fovy: Deg(self.camera.get_zoom()).into(),
+            aspect: width as f32 / height as f32,
+            near: 0.1,
+            far: 100.0
+        }.into();
+        let model = Matrix4::from_translation((0.0, 0.0, 0.0).into());
+        let draw_cube = DebugGroup::push("Draw cube");
+        lightning_shader.set_used();
+        lightning_shader.set_uniform("viewPos", self.camera.get_position());
+        lightning_shader.set_uniform("view", self.camera.get_view_matrix());
+        lightning_shader.set_uniform("projection", projection);
+        lightning_shader.set_uniform("model", model);
+
+        self.cube.draw();
+        drop(draw_cube);
+
+        let draw_lamp = DebugGroup::push("Draw lamp");
+        let mut model = Matrix4::from_translation(self.light_pos);
+        model = model * Matrix4::from_scale(0.2);
+        lamp_shader.set_used();
+        lamp_shader.set_uniform("view", self.camera.get_view_matrix());
+        lamp_shader.set_uniform("projection", projection);
+        lamp_shader.set_uniform("model", model);
+
+        self.light_vao.draw();
+        drop(draw_lamp);
+    }
+}
@@ -0,0 +1,174 @@
+// Saving what the window shows as PNG files. A plain capture reads the
+// default framebuffer, a supersampled one renders the frame again into a
+// bigger offscreen framebuffer and averages it down to the window size.
+use gl;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use stb_image;
+
+use render_gl;
+use render_gl::{ColorFormat, DepthFormat, DepthStorage, Framebuffer};
+
+// RGB, the window's alpha is meaningless
+const CHANNELS: i32 = 3;
+
+// Most samples per pixel along each axis, the offscreen framebuffer gets big fast
+pub const MAX_SUPERSAMPLE: u32 = 4;
+
+pub struct Screenshot {
+    pub width: u32,
+    pub height: u32,
+    // RGB, top row first
+    pub pixels: Vec<u8>,
+}
+
+impl Screenshot {
+    // Reads the back buffer of the window, call it after drawing and before swapping
+    pub fn from_window(width: u32, height: u32) -> Self {
+        unsafe {
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, 0);
+            gl::ReadBuffer(gl::BACK);
+        }
+        let pixels = render_gl::read_pixels(0, 0, width, height, CHANNELS);
+        Screenshot { width, height, pixels }
+    }
+
+    // Calls draw with the size to render at while an offscreen framebuffer
+    // factor times the size is bound, then box filters it down. The bound
+    // framebuffers and the viewport are restored afterwards.
+    pub fn supersampled<F: FnOnce(u32, u32)>(width: u32, height: u32, factor: u32, draw: F) -> Result<Self, String> {
+        let factor = factor.clamp(1, MAX_SUPERSAMPLE);
+        let (big_width, big_height) = (width * factor, height * factor);
+        let (mut draw_framebuffer, mut read_framebuffer, mut viewport) = (0, 0, [0; 4]);
+        unsafe {
+            gl::GetIntegerv(gl::DRAW_FRAMEBUFFER_BINDING, &mut draw_framebuffer);
+            gl::GetIntegerv(gl::READ_FRAMEBUFFER_BINDING, &mut read_framebuffer);
+            gl::GetIntegerv(gl::VIEWPORT, viewport.as_mut_ptr());
+        }
+        let mut target = Framebuffer::builder(big_width, big_height)
+            .color(ColorFormat::Rgba8)
+            .depth(DepthFormat::Depth24Stencil8, DepthStorage::Renderbuffer)
+            .build()
+            .map_err(|err| format!("Screenshot framebuffer: {}", err))?;
+        target.set_label("screenshot");
+
+        target.bind();
+        draw(big_width, big_height);
+        let pixels = render_gl::read_pixels(0, 0, big_width, big_height, CHANNELS);
+        unsafe {
+            gl::BindFramebuffer(gl::DRAW_FRAMEBUFFER, draw_framebuffer as gl::types::GLuint);
+            gl::BindFramebuffer(gl::READ_FRAMEBUFFER, read_framebuffer as gl::types::GLuint);
+            gl::Viewport(viewport[0], viewport[1], viewport[2], viewport[3]);
+        }
+
+        Ok(Screenshot { width, height, pixels: downsample(&pixels, big_width, big_height, factor) })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).map_err(|err| format!("Couldn't create {}: {}", directory.display(), err))?;
+        }
+        stb_image::write_png(&path.to_string_lossy(), self.width, self.height, CHANNELS, &self.pixels)
+            .map_err(|err| err.to_string())
+    }
+
+    // Saves as screenshot-<UTC date and time>.png in the directory and returns the path
+    pub fn save_timestamped(&self, directory: &Path) -> Result<PathBuf, String> {
+        let path = directory.join(format!("screenshot-{}.png", timestamp(SystemTime::now())));
+        self.save(&path).map(|()| path)
+    }
+}
+
+// Averages factor x factor blocks of RGB pixels
+fn downsample(pixels: &[u8], width: u32, height: u32, factor: u32) -> Vec<u8> {
+    let channels = CHANNELS as usize;
+    let (factor, width) = (factor as usize, width as usize);
+    let (small_width, small_height) = (width / factor, height as usize / factor);
+    let samples = (factor * factor) as u32;
+
+    let mut small = Vec::with_capacity(small_width * small_height * channels);
+    for y in 0..small_height {
+        for x in 0..small_width {
+            let mut sum = [0u32; 3];
+            for sy in y * factor..(y + 1) * factor {
+                let row = &pixels[(sy * width + x * factor) * channels..(sy * width + (x + 1) * factor) * channels];
+                for sample in row.chunks(channels) {
+                    for (total, &value) in sum.iter_mut().zip(sample.iter()) {
+                        *total += value as u32;
+                    }
+                }
+            }
+            small.extend(sum.iter().map(|&total| ((total + samples / 2) / samples) as u8));
+        }
+    }
+    small
+}
+
+// 20181018-091502-123, UTC since there's no time zone database to ask
+fn timestamp(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let (year, month, day) = civil_from_days((seconds / 86400) as i64);
+    let seconds_of_day = seconds % 86400;
+    format!(
+        "{:04}{:02}{:02}-{:02}{:02}{:02}-{:03}",
+        year, month, day,
+        seconds_of_day / 3600, seconds_of_day / 60 % 60, seconds_of_day % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+// Days since 1970-01-01 to a (year, month, day) date, from Howard Hinnant's
+// chrono-compatible date algorithms
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let day_of_era = (z - era * 146_097) as u32;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era as i64 + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn averages_each_block() {
+        // 4x2 RGB pixels down to 2x1
+        let pixels = [
+            0, 0, 0,   10, 20, 30,   255, 255, 255,   255, 255, 255,
+            1, 2, 3,   11, 21, 31,   255, 255, 255,   254, 254, 254,
+        ];
+        // (0 + 10 + 1 + 11) / 4 = 5.5 rounds up, 255 * 3 + 254 = 1019 / 4 = 254.75 rounds to 255
+        assert_eq!(downsample(&pixels, 4, 2, 2), vec![6, 11, 16, 255, 255, 255]);
+        assert_eq!(downsample(&pixels, 4, 2, 1), pixels.to_vec());
+        // Leftover rows and columns that don't fill a block are dropped
+        assert_eq!(downsample(&pixels[..9], 3, 1, 2), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn converts_days_to_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        // 2000 is a leap year, 1900 and 2100 aren't
+        assert_eq!(civil_from_days(11016), (2000, 2, 29));
+        assert_eq!(civil_from_days(11017), (2000, 3, 1));
+        assert_eq!(civil_from_days(-25508), (1900, 3, 1));
+        assert_eq!(civil_from_days(47541), (2100, 3, 1));
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        let time = UNIX_EPOCH + Duration::from_millis(1_539_854_102_123);
+        assert_eq!(timestamp(time), "20181018-091502-123");
+        assert_eq!(timestamp(UNIX_EPOCH), "19700101-000000-000");
+    }
+}
@@ -0,0 +1,346 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::fs;
+use std::ops::Index;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::SystemTime;
+
+use gl;
+
+use render_gl::{gl4, Program, Shader, ShaderError, Preprocessor};
+
+// One stage of a program. The embedded source comes from include_str! and is
+// used in release builds, or when the file can't be read.
+#[derive(Clone, Copy, Debug)]
+pub struct StageSource {
+    pub kind: gl::types::GLenum,
+    // Relative to the library's root directory
+    pub file: &'static str,
+    pub embedded: &'static str,
+}
+
+impl StageSource {
+    pub fn vertex(file: &'static str, embedded: &'static str) -> Self {
+        StageSource { kind: gl::VERTEX_SHADER, file, embedded }
+    }
+
+    pub fn fragment(file: &'static str, embedded: &'static str) -> Self {
+        StageSource { kind: gl::FRAGMENT_SHADER, file, embedded }
+    }
+
+    #[cfg(not(feature = "gles30"))]
+    pub fn geometry(file: &'static str, embedded: &'static str) -> Self {
+        StageSource { kind: gl::GEOMETRY_SHADER, file, embedded }
+    }
+
+    pub fn tess_control(file: &'static str, embedded: &'static str) -> Self {
+        StageSource { kind: gl4::TESS_CONTROL_SHADER, file, embedded }
+    }
+
+    pub fn tess_evaluation(file: &'static str, embedded: &'static str) -> Self {
+        StageSource { kind: gl4::TESS_EVALUATION_SHADER, file, embedded }
+    }
+}
+
+// Defines that select a permutation of a program, like DIFFUSE_MAP or
+// a light count. Equal sets always give the same variant.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Features {
+    defines: BTreeMap<String, String>,
+}
+
+impl Features {
+    pub fn new() -> Self {
+        Features::default()
+    }
+
+    // Defines the name as 1
+    pub fn flag(self, name: &str) -> Self {
+        self.value(name, 1)
+    }
+
+    // Adds the flag only when enabled is true
+    pub fn flag_if(self, name: &str, enabled: bool) -> Self {
+        if enabled { self.flag(name) } else { self }
+    }
+
+    pub fn value<T: Display>(mut self, name: &str, value: T) -> Self {
+        self.defines.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.defines.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.defines.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VariantStats {
+    // Variants compiled, counting rebuilds after a reload
+    pub built: usize,
+    pub failed: usize,
+    // Lookups served from the cache without compiling
+    pub cached: usize,
+}
+
+struct Variant {
+    // The last good build, kept while a rebuild fails
+    program: Option<Rc<Program>>,
+    error: Option<ShaderError>,
+    // Set when the program's files changed, rebuilt on the next lookup
+    stale: bool,
+}
+
+struct Entry {
+    stages: Vec<StageSource>,
+    // Every file the program was built from, including #includes, with its
+    // modification time when it was last read (None if it couldn't be)
+    files: Vec<(String, Option<SystemTime>)>,
+    program: Program,
+    variants: RefCell<HashMap<Features, Variant>>,
+}
+
+// Named programs that are recompiled when their files change on disk.
+// Programs are replaced in place, so look them up again after calling poll.
+pub struct ShaderLibrary {
+    root: PathBuf,
+    hot_reload: bool,
+    // Embedded sources of the files shaders can #include
+    includes: HashMap<String, &'static str>,
+    defines: Vec<(String, String)>,
+    programs: HashMap<String, Entry>,
+    stats: Cell<VariantStats>,
+}
+
+impl ShaderLibrary {
+    // Hot reloading is on for debug builds
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        ShaderLibrary {
+            root: root.as_ref().to_path_buf(),
+            hot_reload: cfg!(debug_assertions),
+            includes: HashMap::new(),
+            defines: Vec::new(),
+            programs: HashMap::new(),
+            stats: Cell::new(VariantStats::default()),
+        }
+    }
+
+    pub fn set_hot_reload(&mut self, hot_reload: bool) {
+        self.hot_reload = hot_reload;
+    }
+
+    pub fn hot_reload(&self) -> bool {
+        self.hot_reload
+    }
+
+    // Makes a file available to #include, file is relative to the root like
+    // the include paths are after resolving
+    pub fn add_include(&mut self, file: &str, embedded: &'static str) {
+        self.includes.insert(file.to_string(), embedded);
+    }
+
+    // Injected into every program compiled after this, including reloads
+    pub fn define(&mut self, name: &str, value: &str) {
+        self.defines.retain(|(n, _)| n != name);
+        self.defines.push((name.to_string(), value.to_string()));
+    }
+
+    // Compiles the program, replacing any program already using the name
+    pub fn add(&mut self, name: &str, stages: &[StageSource]) -> Result<(), ShaderError> {
+        let (program, files) = self.compile(stages, &Features::new())?;
+        program.set_label(name);
+        let files = files.into_iter().map(|file| {
+            let modified = self.modified(&file);
+            (file, modified)
+        }).collect();
+        let entry = Entry { stages: stages.to_vec(), files, program, variants: RefCell::new(HashMap::new()) };
+        self.programs.insert(name.to_string(), entry);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Program> {
+        self.programs.get(name).map(|entry| &entry.program)
+    }
+
+    // The program compiled with the features defined on top of the library's
+    // defines. Each permutation is compiled on first use and cached. After a
+    // reload a variant that fails to rebuild keeps its last good program, so
+    // this only fails for variants that never compiled.
+    pub fn variant(&self, name: &str, features: &Features) -> Result<Rc<Program>, ShaderError> {
+        let entry = match self.programs.get(name) {
+            Some(entry) => entry,
+            None => panic!("No shader named \"{}\" in the library", name),
+        };
+        let mut stats = self.stats.get();
+        let mut variants = entry.variants.borrow_mut();
+
+        if let Some(variant) = variants.get(features) {
+            if !variant.stale {
+                stats.cached += 1;
+                self.stats.set(stats);
+                return match variant.program {
+                    Some(ref program) => Ok(program.clone()),
+                    None => Err(variant.error.clone().unwrap()),
+                };
+            }
+        }
+
+        let variant = variants.entry(features.clone()).or_insert(Variant { program: None, error: None, stale: true });
+        variant.stale = false;
+        match self.compile(&entry.stages, features) {
+            Ok((program, _)) => {
+                program.set_label(&variant_label(name, features));
+                stats.built += 1;
+                variant.program = Some(Rc::new(program));
+                variant.error = None;
+            },
+            Err(err) => {
+                println!("Couldn't build the {:?} variant of \"{}\": {}", features, name, err);
+                stats.failed += 1;
+                variant.error = Some(err);
+            },
+        }
+        self.stats.set(stats);
+
+        match variant.program {
+            Some(ref program) => Ok(program.clone()),
+            None => Err(variant.error.clone().unwrap()),
+        }
+    }
+
+    pub fn stats(&self) -> VariantStats {
+        self.stats.get()
+    }
+
+    // Permutations in the cache across every program, including failed ones
+    pub fn variant_count(&self) -> usize {
+        self.programs.values().map(|entry| entry.variants.borrow().len()).sum()
+    }
+
+    // Recompiles every program with a changed file and returns the names of the
+    // ones that were replaced. A program that fails to compile keeps the last
+    // good version until its files change again.
+    pub fn poll(&mut self) -> Vec<String> {
+        if !self.hot_reload {
+            return Vec::new();
+        }
+
+        let mut reloaded = Vec::new();
+        let names: Vec<String> = self.programs.keys().cloned().collect();
+        for name in names {
+            let stages = {
+                let entry = &self.programs[&name];
+                if entry.files.iter().all(|(file, modified)| self.modified(file) == *modified) {
+                    continue;
+                }
+                entry.stages.clone()
+            };
+
+            let result = self.compile(&stages, &Features::new());
+            let files = match result {
+                Ok((_, ref files)) => files.clone(),
+                // The includes may not be known, so keep watching the old files
+                Err(_) => self.programs[&name].files.iter().map(|(file, _)| file.clone()).collect(),
+            };
+            let files = files.into_iter().map(|file| {
+                let modified = self.modified(&file);
+                (file, modified)
+            }).collect();
+
+            let entry = self.programs.get_mut(&name).unwrap();
+            entry.files = files;
+            for variant in entry.variants.get_mut().values_mut() {
+                variant.stale = true;
+            }
+            match result {
+                Ok((program, _)) => {
+                    println!("Reloaded shader \"{}\"", name);
+                    program.set_label(&name);
+                    entry.program = program;
+                    reloaded.push(name);
+                },
+                Err(err) => println!("Keeping the previous \"{}\" shader: {}", name, err),
+            }
+        }
+        reloaded
+    }
+
+    // Returns the program and the names of every file that went into it
+    fn compile(&self, stages: &[StageSource], features: &Features) -> Result<(Program, Vec<String>), ShaderError> {
+        let mut preprocessor = Preprocessor::new(|file| self.read_include(file));
+        for (define, value) in self.defines.iter() {
+            preprocessor.define(define, value);
+        }
+        for (define, value) in features.iter() {
+            preprocessor.define(define, value);
+        }
+
+        let mut shaders = Vec::with_capacity(stages.len());
+        let mut files: Vec<String> = Vec::new();
+        for stage in stages.iter() {
+            let source = preprocessor.run(stage.file, &self.read(stage.file, Some(stage.embedded)).unwrap_or_default())?;
+            for file in source.files.iter() {
+                if !files.contains(&file.name) {
+                    files.push(file.name.clone());
+                }
+            }
+            shaders.push(Shader::from_preprocessed(&source, stage.kind)?);
+        }
+        Program::link(&shaders).map(|program| (program, files))
+    }
+
+    fn read_include(&self, file: &str) -> Result<String, String> {
+        self.read(file, self.includes.get(file).cloned())
+            .ok_or_else(|| String::from("no such file, add it with add_include"))
+    }
+
+    // Reads the file from disk when hot reloading, otherwise or when that
+    // fails uses the embedded source
+    fn read(&self, file: &str, embedded: Option<&'static str>) -> Option<String> {
+        if !self.hot_reload {
+            return embedded.map(String::from);
+        }
+        let path = self.root.join(file);
+        match fs::read_to_string(&path) {
+            Ok(source) => Some(source),
+            Err(err) => {
+                if embedded.is_some() {
+                    println!("Couldn't read {}, using the built in source: {}", path.display(), err);
+                }
+                embedded.map(String::from)
+            }
+        }
+    }
+
+    fn modified(&self, file: &str) -> Option<SystemTime> {
+        if !self.hot_reload {
+            return None;
+        }
+        fs::metadata(self.root.join(file)).and_then(|metadata| metadata.modified()).ok()
+    }
+}
+
+// "lighting [DIFFUSE_MAP, MAX_LIGHTS=4]"
+fn variant_label(name: &str, features: &Features) -> String {
+    let defines: Vec<String> = features.iter().map(|(define, value)| {
+        if value == "1" { define.to_string() } else { format!("{}={}", define, value) }
+    }).collect();
+    format!("{} [{}]", name, defines.join(", "))
+}
+
+impl Index<&str> for ShaderLibrary {
+    type Output = Program;
+
+    fn index(&self, name: &str) -> &Program {
+        match self.get(name) {
+            Some(program) => program,
+            None => panic!("No shader named \"{}\" in the library", name),
+        }
+    }
+}
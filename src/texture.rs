@@ -0,0 +1,382 @@
+use gl;
+use std;
+use std::fmt;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+use stb_image;
+
+use render_gl::debug;
+
+#[derive(Debug)]
+pub enum TextureError {
+    Load { path: String, reason: stb_image::Error },
+    UnsupportedChannels(i32),
+    // Negative, or too large to address
+    InvalidSize { width: i32, height: i32 },
+    InvalidData { expected: usize, actual: usize },
+}
+
+impl fmt::Display for TextureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TextureError::Load { ref path, ref reason } => write!(f, "Couldn't load \"{}\": {}", path, reason),
+            TextureError::UnsupportedChannels(n) => write!(f, "Unsupported channel count {}", n),
+            TextureError::InvalidSize { width, height } => write!(f, "Invalid texture size {}x{}", width, height),
+            TextureError::InvalidData { expected, actual } =>
+                write!(f, "Expected {} bytes of pixel data, got {}", expected, actual),
+        }
+    }
+}
+
+impl std::error::Error for TextureError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            TextureError::Load { ref reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Wrap {
+    Repeat,
+    MirroredRepeat,
+    ClampToEdge,
+    #[cfg(not(feature = "gles30"))]
+    ClampToBorder,
+}
+
+impl Wrap {
+    fn to_gl(self) -> gl::types::GLenum {
+        match self {
+            Wrap::Repeat => gl::REPEAT,
+            Wrap::MirroredRepeat => gl::MIRRORED_REPEAT,
+            Wrap::ClampToEdge => gl::CLAMP_TO_EDGE,
+            #[cfg(not(feature = "gles30"))]
+            Wrap::ClampToBorder => gl::CLAMP_TO_BORDER,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Filter {
+    Nearest,
+    Linear,
+}
+
+#[derive(Debug)]
+pub struct Texture2D {
+    id: gl::types::GLuint,
+    width: i32,
+    height: i32,
+}
+
+impl Texture2D {
+    pub fn builder() -> Texture2DBuilder {
+        Texture2DBuilder::new()
+    }
+
+    // Loads with the default options, see Texture2DBuilder
+    pub fn from_file(path: &str) -> Result<Self, TextureError> {
+        Texture2DBuilder::new().load(path)
+    }
+
+    pub fn id(&self) -> gl::types::GLuint { self.id }
+    pub fn width(&self) -> i32 { self.width }
+    pub fn height(&self) -> i32 { self.height }
+
+    // Names the texture in debug output and captures
+    pub fn set_label(&self, name: &str) {
+        debug::label(debug::Object::Texture, self.id, name);
+    }
+
+    // Binds to texture unit GL_TEXTURE0 + unit
+    pub fn bind(&self, unit: u32) {
+        unsafe {
+            gl::ActiveTexture(gl::TEXTURE0 + unit);
+            gl::BindTexture(gl::TEXTURE_2D, self.id);
+        }
+    }
+}
+
+impl Drop for Texture2D {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteTextures(1, &self.id);
+        }
+    }
+}
+
+// Defaults match what learnopengl uses: repeat, linear filtering, mipmaps and
+// flipped so the first row is the bottom of the image.
+#[derive(Clone, Debug)]
+pub struct Texture2DBuilder {
+    wrap_s: Wrap,
+    wrap_t: Wrap,
+    min_filter: Filter,
+    mag_filter: Filter,
+    mipmaps: bool,
+    srgb: bool,
+    flip_vertically: bool,
+    premultiply_alpha: bool,
+}
+
+impl Texture2DBuilder {
+    pub fn new() -> Self {
+        Texture2DBuilder {
+            wrap_s: Wrap::Repeat,
+            wrap_t: Wrap::Repeat,
+            min_filter: Filter::Linear,
+            mag_filter: Filter::Linear,
+            mipmaps: true,
+            srgb: false,
+            flip_vertically: true,
+            premultiply_alpha: false,
+        }
+    }
+
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap_s = wrap;
+        self.wrap_t = wrap;
+        self
+    }
+
+    pub fn wrap_st(mut self, wrap_s: Wrap, wrap_t: Wrap) -> Self {
+        self.wrap_s = wrap_s;
+        self.wrap_t = wrap_t;
+        self
+    }
+
+    pub fn filter(mut self, min_filter: Filter, mag_filter: Filter) -> Self {
+        self.min_filter = min_filter;
+        self.mag_filter = mag_filter;
+        self
+    }
+
+    pub fn mipmaps(mut self, mipmaps: bool) -> Self {
+        self.mipmaps = mipmaps;
+        self
+    }
+
+    // Color textures should be sRGB, data textures like normal maps shouldn't
+    pub fn srgb(mut self, srgb: bool) -> Self {
+        self.srgb = srgb;
+        self
+    }
+
+    pub fn flip_vertically(mut self, flip: bool) -> Self {
+        self.flip_vertically = flip;
+        self
+    }
+
+    pub fn premultiply_alpha(mut self, premultiply: bool) -> Self {
+        self.premultiply_alpha = premultiply;
+        self
+    }
+
+    // Decoding doesn't touch gl, so this part can run on any thread
+    pub fn decode(&self, path: &str) -> Result<stb_image::Image<u8>, TextureError> {
+        self.load_options()
+            .load(path)
+            .map_err(|reason| TextureError::Load { path: path.to_string(), reason })
+    }
+
+    pub fn decode_memory(&self, bytes: &[u8]) -> Result<stb_image::Image<u8>, TextureError> {
+        self.load_options()
+            .load_from_memory(bytes)
+            .map_err(|reason| TextureError::Load { path: String::from("<memory>"), reason })
+    }
+
+    pub fn load(&self, path: &str) -> Result<Texture2D, TextureError> {
+        let image = self.decode(path)?;
+        self.upload_image(&image)
+    }
+
+    // Loads an encoded image file (png, jpg...) that's already in memory
+    pub fn load_from_memory(&self, bytes: &[u8]) -> Result<Texture2D, TextureError> {
+        let image = self.decode_memory(bytes)?;
+        self.upload_image(&image)
+    }
+
+    fn load_options(&self) -> stb_image::LoadOptions {
+        stb_image::LoadOptions {
+            desired_channels: 0,
+            flip_vertically: self.flip_vertically,
+            premultiply_alpha: self.premultiply_alpha,
+        }
+    }
+
+    pub fn upload_image(&self, image: &stb_image::Image<u8>) -> Result<Texture2D, TextureError> {
+        self.upload(image.width(), image.height(), image.channels(), image.as_slice())
+    }
+
+    // Uploads tightly packed 8 bit pixels, bottom row first
+    pub fn upload(&self, width: i32, height: i32, channels: i32, pixels: &[u8]) -> Result<Texture2D, TextureError> {
+        let (internal_format, format, swizzle) = match channels {
+            1 => (gl::R8, gl::RED, [gl::RED, gl::RED, gl::RED, gl::ONE]),
+            2 => (gl::RG8, gl::RG, [gl::RED, gl::RED, gl::RED, gl::GREEN]),
+            3 => (if self.srgb { gl::SRGB8 } else { gl::RGB8 }, gl::RGB, [gl::RED, gl::GREEN, gl::BLUE, gl::ONE]),
+            4 => (if self.srgb { gl::SRGB8_ALPHA8 } else { gl::RGBA8 }, gl::RGBA, [gl::RED, gl::GREEN, gl::BLUE, gl::ALPHA]),
+            n => return Err(TextureError::UnsupportedChannels(n)),
+        };
+        let expected = pixel_bytes(width, height, channels).ok_or(TextureError::InvalidSize { width, height })?;
+        if pixels.len() != expected {
+            return Err(TextureError::InvalidData { expected, actual: pixels.len() });
+        }
+
+        let min_filter = match (self.min_filter, self.mipmaps) {
+            (Filter::Nearest, false) => gl::NEAREST,
+            (Filter::Linear, false) => gl::LINEAR,
+            (Filter::Nearest, true) => gl::NEAREST_MIPMAP_LINEAR,
+            (Filter::Linear, true) => gl::LINEAR_MIPMAP_LINEAR,
+        };
+        let mag_filter = match self.mag_filter {
+            Filter::Nearest => gl::NEAREST,
+            Filter::Linear => gl::LINEAR,
+        };
+
+        let mut id = 0;
+        unsafe {
+            gl::GenTextures(1, &mut id);
+            gl::BindTexture(gl::TEXTURE_2D, id);
+
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_S, self.wrap_s.to_gl() as gl::types::GLint);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_WRAP_T, self.wrap_t.to_gl() as gl::types::GLint);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, min_filter as gl::types::GLint);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, mag_filter as gl::types::GLint);
+            // Grey and grey + alpha images sample like rgb(a) ones
+            let swizzle_parameters = [gl::TEXTURE_SWIZZLE_R, gl::TEXTURE_SWIZZLE_G, gl::TEXTURE_SWIZZLE_B, gl::TEXTURE_SWIZZLE_A];
+            for (&parameter, &source) in swizzle_parameters.iter().zip(swizzle.iter()) {
+                gl::TexParameteri(gl::TEXTURE_2D, parameter, source as gl::types::GLint);
+            }
+
+            // Rows of 1-3 channel images aren't necessarily 4 byte aligned
+            gl::PixelStorei(gl::UNPACK_ALIGNMENT, 1);
+            gl::TexImage2D(
+                gl::TEXTURE_2D,
+                0,
+                internal_format as gl::types::GLint,
+                width, height,
+                0,
+                format,
+                gl::UNSIGNED_BYTE,
+                pixels.as_ptr() as *const gl::types::GLvoid
+            );
+            gl::PixelStorei(gl::UNPACK_ALIGNMENT, 4);
+
+            if self.mipmaps {
+                gl::GenerateMipmap(gl::TEXTURE_2D);
+            }
+        }
+        Ok(Texture2D { id, width, height })
+    }
+}
+
+type Decoded = (String, Texture2DBuilder, Result<stb_image::Image<u8>, TextureError>);
+
+// Bytes of tightly packed 8 bit pixels, None for negative or overflowing sizes
+fn pixel_bytes(width: i32, height: i32, channels: i32) -> Option<usize> {
+    if width < 0 || height < 0 || channels < 0 {
+        return None;
+    }
+    (width as usize).checked_mul(height as usize)?.checked_mul(channels as usize)
+}
+
+// Decodes images on worker threads. Textures are created on the gl thread
+// when it calls poll() or wait().
+pub struct TextureLoader {
+    jobs: Option<mpsc::Sender<(String, Texture2DBuilder)>>,
+    decoded: mpsc::Receiver<Decoded>,
+    workers: Vec<thread::JoinHandle<()>>,
+    pending: usize,
+}
+
+impl TextureLoader {
+    pub fn new(threads: usize) -> Self {
+        let (job_sender, job_receiver) = mpsc::channel::<(String, Texture2DBuilder)>();
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+        let (decoded_sender, decoded) = mpsc::channel();
+
+        let workers = (0..threads.max(1)).map(|_| {
+            let job_receiver = job_receiver.clone();
+            let decoded_sender = decoded_sender.clone();
+            thread::spawn(move || loop {
+                let job = job_receiver.lock().unwrap().recv();
+                let (path, builder) = match job {
+                    Ok(job) => job,
+                    // Loader was dropped
+                    Err(_) => break,
+                };
+                let image = builder.decode(&path);
+                if decoded_sender.send((path, builder, image)).is_err() {
+                    break;
+                }
+            })
+        }).collect();
+
+        TextureLoader { jobs: Some(job_sender), decoded, workers, pending: 0 }
+    }
+
+    pub fn request(&mut self, path: &str, builder: Texture2DBuilder) {
+        if let Some(ref jobs) = self.jobs {
+            jobs.send((path.to_string(), builder)).unwrap();
+            self.pending += 1;
+        }
+    }
+
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+
+    // Uploads whatever has finished decoding without blocking
+    pub fn poll(&mut self) -> Vec<(String, Result<Texture2D, TextureError>)> {
+        let mut done = Vec::new();
+        while let Ok(decoded) = self.decoded.try_recv() {
+            done.push(self.finish(decoded));
+        }
+        done
+    }
+
+    // Blocks until every requested texture is uploaded
+    pub fn wait(&mut self) -> Vec<(String, Result<Texture2D, TextureError>)> {
+        let mut done = Vec::new();
+        while self.pending > 0 {
+            match self.decoded.recv() {
+                Ok(decoded) => done.push(self.finish(decoded)),
+                Err(_) => break,
+            }
+        }
+        done
+    }
+
+    fn finish(&mut self, (path, builder, image): Decoded) -> (String, Result<Texture2D, TextureError>) {
+        self.pending -= 1;
+        let texture = image.and_then(|image| builder.upload_image(&image));
+        (path, texture)
+    }
+}
+
+impl Drop for TextureLoader {
+    fn drop(&mut self) {
+        // Closing the channel stops the workers
+        self.jobs.take();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_pixel_data_without_overflowing() {
+        assert_eq!(pixel_bytes(3, 2, 4), Some(24));
+        // Past i32::MAX bytes, which multiplying as i32 would wrap
+        assert_eq!(pixel_bytes(40000, 40000, 4), Some(6_400_000_000));
+        assert_eq!(pixel_bytes(-1, 2, 4), None);
+        assert_eq!(TextureError::InvalidSize { width: -1, height: 2 }.to_string(), "Invalid texture size -1x2");
+    }
+}
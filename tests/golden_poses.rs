@@ -0,0 +1,21 @@
+// Runs the golden image check (--golden) through the binary, so it needs a
+// headless backend: cargo test --features headless-egl
+#![cfg(any(feature = "headless-egl", feature = "headless-osmesa"))]
+
+use std::process::Command;
+
+#[cfg(feature = "headless-egl")]
+const BACKEND: &str = "egl";
+#[cfg(not(feature = "headless-egl"))]
+const BACKEND: &str = "osmesa";
+
+#[test]
+fn golden_poses_match() {
+    let output = Command::new(env!("CARGO_BIN_EXE_learning-opengl"))
+        .args(["--golden", "--headless", BACKEND])
+        .env_remove("BLESS_GOLDEN")
+        .output()
+        .expect("Couldn't run learning-opengl");
+    assert!(output.status.success(), "Golden check failed:\n{}{}",
+        String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+}